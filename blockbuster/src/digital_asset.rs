@@ -0,0 +1,399 @@
+use crate::{
+    error::BlockbusterError,
+    programs::{
+        bubblegum::{BubblegumInstruction, Payload},
+        mpl_core_program::{MplCoreAccountData, MplCoreAccountState},
+        token_extensions::{extension::ShadowMetadata, MintAccount, TokenExtensionsProgramAccount},
+        token_metadata::{
+            normalize::NormalizedMetadata, TokenMetadataAccountData, TokenMetadataAccountState,
+        },
+    },
+};
+use mpl_bubblegum::{
+    types::{LeafSchema, MetadataArgs, MetadataArgsV2},
+    utils::get_asset_id,
+    Flags,
+};
+use mpl_core::{
+    types::{Plugin, PluginAuthority, UpdateAuthority},
+    IndexableAsset,
+};
+use mpl_token_metadata::accounts::Metadata;
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// A creator of a `DigitalAsset`, with its share of royalties.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AssetCreator {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+    pub address: Pubkey,
+    /// Percentage of royalties paid to this creator.
+    pub share: u8,
+    pub verified: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AssetCollection {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+    pub address: Pubkey,
+    pub verified: bool,
+}
+
+/// One asset shape for Token Metadata, Bubblegum, MPL Core and Token-2022 metadata.
+///
+/// Each source only knows part of the asset: fields a source does not store are left at their
+/// default, e.g. a `Metadata` account has no owner and an MPL Core account does not store its own
+/// address.  Set those from other accounts or the account address where needed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DigitalAsset {
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub id: Option<Pubkey>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub owner: Option<Pubkey>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub delegate: Option<Pubkey>,
+    /// Accounts allowed to update the asset.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::vec_pubkey")
+    )]
+    pub authorities: Vec<Pubkey>,
+    pub creators: Vec<AssetCreator>,
+    pub royalty_basis_points: u16,
+    pub collection: Option<AssetCollection>,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub frozen: bool,
+    pub burnt: bool,
+    pub supply: Option<u64>,
+}
+
+impl From<&Metadata> for DigitalAsset {
+    fn from(metadata: &Metadata) -> Self {
+        // Token Metadata pads its strings with `\0`.
+        let normalized = NormalizedMetadata::new(metadata);
+
+        DigitalAsset {
+            id: Some(metadata.mint),
+            authorities: vec![metadata.update_authority],
+            creators: metadata
+                .creators
+                .iter()
+                .flatten()
+                .map(|creator| AssetCreator {
+                    address: creator.address,
+                    share: creator.share,
+                    verified: creator.verified,
+                })
+                .collect(),
+            royalty_basis_points: metadata.seller_fee_basis_points,
+            collection: metadata
+                .collection
+                .as_ref()
+                .map(|collection| AssetCollection {
+                    address: collection.key,
+                    verified: collection.verified,
+                }),
+            name: normalized.name,
+            symbol: normalized.symbol,
+            uri: normalized.uri,
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<&TokenMetadataAccountState> for DigitalAsset {
+    type Error = BlockbusterError;
+
+    fn try_from(state: &TokenMetadataAccountState) -> Result<Self, Self::Error> {
+        match &state.data {
+            TokenMetadataAccountData::MetadataV1(metadata) => Ok(metadata.into()),
+            _ => Err(BlockbusterError::InvalidAccountType),
+        }
+    }
+}
+
+impl From<&MetadataArgs> for DigitalAsset {
+    fn from(args: &MetadataArgs) -> Self {
+        DigitalAsset {
+            creators: args
+                .creators
+                .iter()
+                .map(|creator| AssetCreator {
+                    address: creator.address,
+                    share: creator.share,
+                    verified: creator.verified,
+                })
+                .collect(),
+            royalty_basis_points: args.seller_fee_basis_points,
+            collection: args.collection.as_ref().map(|collection| AssetCollection {
+                address: collection.key,
+                verified: collection.verified,
+            }),
+            name: args.name.clone(),
+            symbol: args.symbol.clone(),
+            uri: args.uri.clone(),
+            supply: Some(1),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&MetadataArgsV2> for DigitalAsset {
+    fn from(args: &MetadataArgsV2) -> Self {
+        DigitalAsset {
+            creators: args
+                .creators
+                .iter()
+                .map(|creator| AssetCreator {
+                    address: creator.address,
+                    share: creator.share,
+                    verified: creator.verified,
+                })
+                .collect(),
+            royalty_basis_points: args.seller_fee_basis_points,
+            // V2 collections are always verified.
+            collection: args.collection.map(|address| AssetCollection {
+                address,
+                verified: true,
+            }),
+            name: args.name.clone(),
+            symbol: args.symbol.clone(),
+            uri: args.uri.clone(),
+            supply: Some(1),
+            ..Default::default()
+        }
+    }
+}
+
+/// Converts Bubblegum mints and burns.  The asset id, owner and delegate of a mint come from its
+/// leaf schema event, so they are `None` if the event was not parsed.
+impl TryFrom<&BubblegumInstruction> for DigitalAsset {
+    type Error = BlockbusterError;
+
+    fn try_from(ix: &BubblegumInstruction) -> Result<Self, Self::Error> {
+        let leaf = ix.leaf_update.as_ref().map(|event| &event.schema);
+        let mut asset = match &ix.payload {
+            Some(Payload::MintV1 {
+                args,
+                tree_creator_or_delegate,
+                ..
+            }) => DigitalAsset {
+                authorities: vec![*tree_creator_or_delegate],
+                ..args.into()
+            },
+            Some(Payload::MintV2 {
+                args,
+                tree_creator_or_delegate,
+                leaf_owner,
+                leaf_delegate,
+                ..
+            }) => DigitalAsset {
+                owner: Some(*leaf_owner),
+                delegate: Some(*leaf_delegate),
+                authorities: vec![*tree_creator_or_delegate],
+                ..args.into()
+            },
+            Some(Payload::Burn {
+                leaf,
+                leaf_owner,
+                leaf_delegate,
+                tree_id,
+                ..
+            })
+            | Some(Payload::BurnV2 {
+                leaf,
+                leaf_owner,
+                leaf_delegate,
+                tree_id,
+                ..
+            }) => {
+                return Ok(DigitalAsset {
+                    id: Some(get_asset_id(tree_id, leaf.nonce)),
+                    owner: Some(*leaf_owner),
+                    delegate: Some(*leaf_delegate),
+                    burnt: true,
+                    ..Default::default()
+                })
+            }
+            _ => return Err(BlockbusterError::InvalidAccountType),
+        };
+
+        if let Some(leaf) = leaf {
+            asset.id = Some(leaf.id());
+            asset.owner = Some(leaf.owner());
+            asset.delegate = Some(leaf.delegate());
+            if let LeafSchema::V2 { flags, .. } = leaf {
+                let flags = Flags::from_bytes([*flags]);
+                asset.frozen = flags.asset_lvl_frozen() || flags.permanent_lvl_frozen();
+            }
+        }
+        Ok(asset)
+    }
+}
+
+/// MPL Core accounts do not store their own address, so `id` is `None`.
+impl From<&IndexableAsset> for DigitalAsset {
+    fn from(asset: &IndexableAsset) -> Self {
+        let mut digital_asset = DigitalAsset {
+            owner: asset.owner,
+            name: asset.name.clone(),
+            uri: asset.uri.clone(),
+            // Collections have no owner and no supply of their own.
+            supply: asset.owner.map(|_| 1),
+            ..Default::default()
+        };
+
+        match &asset.update_authority {
+            UpdateAuthority::Address(address) => digital_asset.authorities.push(*address),
+            UpdateAuthority::Collection(address) => {
+                digital_asset.collection = Some(AssetCollection {
+                    address: *address,
+                    verified: true,
+                })
+            }
+            UpdateAuthority::None => {}
+        }
+
+        // Plugins are kept in a `HashMap`, so gather verified creators before applying them.
+        let mut verified_creators = Vec::new();
+        for plugin in asset.plugins.values() {
+            let delegate = match plugin.authority {
+                PluginAuthority::Address { address } => Some(address),
+                _ => None,
+            };
+            match &plugin.data {
+                Plugin::Royalties(royalties) => {
+                    digital_asset.royalty_basis_points = royalties.basis_points;
+                    digital_asset.creators = royalties
+                        .creators
+                        .iter()
+                        .map(|creator| AssetCreator {
+                            address: creator.address,
+                            share: creator.percentage,
+                            verified: false,
+                        })
+                        .collect();
+                }
+                Plugin::VerifiedCreators(creators) => {
+                    verified_creators = creators
+                        .signatures
+                        .iter()
+                        .filter(|signature| signature.verified)
+                        .map(|signature| signature.address)
+                        .collect();
+                }
+                Plugin::FreezeDelegate(freeze) => digital_asset.frozen |= freeze.frozen,
+                Plugin::PermanentFreezeDelegate(freeze) => digital_asset.frozen |= freeze.frozen,
+                Plugin::TransferDelegate(_) => digital_asset.delegate = delegate,
+                Plugin::UpdateDelegate(_) => digital_asset.authorities.extend(delegate),
+                _ => {}
+            }
+        }
+
+        for address in verified_creators {
+            match digital_asset
+                .creators
+                .iter_mut()
+                .find(|creator| creator.address == address)
+            {
+                Some(creator) => creator.verified = true,
+                None => digital_asset.creators.push(AssetCreator {
+                    address,
+                    share: 0,
+                    verified: true,
+                }),
+            }
+        }
+
+        digital_asset
+    }
+}
+
+/// Burnt MPL Core assets are left as empty accounts, which convert to a burnt asset.
+impl TryFrom<&MplCoreAccountState> for DigitalAsset {
+    type Error = BlockbusterError;
+
+    fn try_from(state: &MplCoreAccountState) -> Result<Self, Self::Error> {
+        match &state.data {
+            MplCoreAccountData::Asset(asset) | MplCoreAccountData::Collection(asset) => {
+                Ok(asset.into())
+            }
+            MplCoreAccountData::EmptyAccount => Ok(DigitalAsset {
+                burnt: true,
+                ..Default::default()
+            }),
+            MplCoreAccountData::HashedAsset(_) => Err(BlockbusterError::InvalidAccountType),
+        }
+    }
+}
+
+impl TryFrom<&ShadowMetadata> for DigitalAsset {
+    type Error = BlockbusterError;
+
+    fn try_from(metadata: &ShadowMetadata) -> Result<Self, Self::Error> {
+        let mint = Pubkey::from_str(&metadata.mint)
+            .map_err(|e| BlockbusterError::CustomDeserializationError(e.to_string()))?;
+
+        Ok(DigitalAsset {
+            id: Some(mint),
+            authorities: Option::<Pubkey>::from(metadata.update_authority)
+                .into_iter()
+                .collect(),
+            name: metadata.name.clone(),
+            symbol: metadata.symbol.clone(),
+            uri: metadata.uri.clone(),
+            ..Default::default()
+        })
+    }
+}
+
+/// Converts a Token-2022 mint with an embedded `TokenMetadata` extension.  Metadata stored in
+/// another account is resolved with `MintAccount::resolve_metadata`.
+impl TryFrom<&MintAccount> for DigitalAsset {
+    type Error = BlockbusterError;
+
+    fn try_from(mint: &MintAccount) -> Result<Self, Self::Error> {
+        let metadata = mint
+            .extensions
+            .metadata
+            .as_ref()
+            .ok_or(BlockbusterError::InvalidAccountType)?;
+        let mut asset = DigitalAsset::try_from(metadata)?;
+
+        asset.supply = Some(mint.account.supply);
+        // Only the group's update authority can add a member, so membership is always verified.
+        if let Some(member) = &mint.extensions.token_group_member {
+            asset.collection = Some(AssetCollection {
+                address: Pubkey::from_str(&member.group)
+                    .map_err(|e| BlockbusterError::CustomDeserializationError(e.to_string()))?,
+                verified: true,
+            });
+        }
+        Ok(asset)
+    }
+}
+
+impl TryFrom<&TokenExtensionsProgramAccount> for DigitalAsset {
+    type Error = BlockbusterError;
+
+    fn try_from(account: &TokenExtensionsProgramAccount) -> Result<Self, Self::Error> {
+        match account {
+            TokenExtensionsProgramAccount::MintAccount(mint) => mint.try_into(),
+            _ => Err(BlockbusterError::InvalidAccountType),
+        }
+    }
+}
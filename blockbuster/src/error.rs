@@ -1,3 +1,4 @@
+use solana_sdk::pubkey::Pubkey;
 use std::io::Error;
 use thiserror::Error;
 
@@ -23,8 +24,34 @@ pub enum BlockbusterError {
     UninitializedAccount,
     #[error("Account Type Not implemented")]
     AccountTypeNotImplemented,
+    #[error("Account {0} is not the expected PDA {1}")]
+    InvalidPda(Pubkey, Pubkey),
+    #[error("Account mint {0} does not match expected mint {1}")]
+    MintMismatch(Pubkey, Pubkey),
+    #[error("Account address cannot be derived from its data")]
+    PdaNotDerivable,
+    #[error("Compression proof does not match hashed asset")]
+    InvalidCompressionProof,
+    #[error("Additional metadata is {0} bytes, over the maximum of {1}")]
+    AdditionalMetadataTooLong(usize, usize),
+    #[error("UI amount is invalid")]
+    InvalidUiAmount,
     #[error("Could not deserialize data: {0}")]
     CustomDeserializationError(String),
+    #[error("Program id index {index} is out of range of {key_count} account keys in instruction {outer_index}, inner instruction {inner_index:?}")]
+    InvalidProgramIdIndex {
+        index: u8,
+        key_count: usize,
+        outer_index: usize,
+        inner_index: Option<usize>,
+    },
+    #[error("Account index {index} is out of range of {key_count} account keys in instruction {outer_index}, inner instruction {inner_index:?}")]
+    InvalidAccountIndex {
+        index: u8,
+        key_count: usize,
+        outer_index: usize,
+        inner_index: Option<usize>,
+    },
 }
 
 impl From<std::io::Error> for BlockbusterError {
@@ -1,5 +1,15 @@
-use solana_sdk::{instruction::CompiledInstruction, pubkey::Pubkey};
-use solana_transaction_status::InnerInstructions;
+use crate::error::BlockbusterError;
+use solana_sdk::{
+    instruction::CompiledInstruction,
+    message::{
+        v0::{LoadedAddresses, LoadedMessage},
+        VersionedMessage,
+    },
+    pubkey::Pubkey,
+};
+use solana_transaction_status::{
+    InnerInstructions, TransactionStatusMeta, VersionedTransactionWithStatusMeta,
+};
 use std::collections::{HashSet, VecDeque};
 
 pub type IxPair<'a> = (Pubkey, &'a CompiledInstruction);
@@ -27,63 +37,287 @@ impl<'a> Default for InstructionBundle<'a> {
     }
 }
 
+/// Returns the account at `index` of an instruction's accounts.
+pub(crate) fn get_key(keys: &[Pubkey], index: usize) -> Result<Pubkey, BlockbusterError> {
+    keys.get(index)
+        .copied()
+        .ok_or(BlockbusterError::InstructionParsingError)
+}
+
+/// Returns the optional account at `index` of an instruction's accounts, or `None` if it was
+/// omitted.  Clients pass `program_id` in place of an omitted optional account, and older clients
+/// may leave off trailing optional accounts entirely.
+pub(crate) fn get_optional_key(
+    keys: &[Pubkey],
+    index: usize,
+    program_id: &Pubkey,
+) -> Option<Pubkey> {
+    keys.get(index).copied().filter(|key| key != program_id)
+}
+
+/// Account keys of a transaction in the order its instructions index them: the message's static
+/// keys, then the writable and the readonly keys loaded from address lookup tables.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionAccountKeys {
+    keys: Vec<Pubkey>,
+    is_signer: Vec<bool>,
+    is_writable: Vec<bool>,
+}
+
+impl TransactionAccountKeys {
+    /// `loaded_addresses` is ignored for legacy messages, which cannot use lookup tables.
+    pub fn new(message: &VersionedMessage, loaded_addresses: &LoadedAddresses) -> Self {
+        let (keys, is_writable): (Vec<Pubkey>, Vec<bool>) = match message {
+            VersionedMessage::Legacy(message) => message
+                .account_keys
+                .iter()
+                .enumerate()
+                .map(|(index, key)| (*key, message.is_writable(index)))
+                .unzip(),
+            VersionedMessage::V0(message) => {
+                let loaded_message = LoadedMessage::new_borrowed(message, loaded_addresses);
+                loaded_message
+                    .account_keys()
+                    .iter()
+                    .enumerate()
+                    .map(|(index, key)| (*key, loaded_message.is_writable(index)))
+                    .unzip()
+            }
+        };
+        let is_signer = (0..keys.len())
+            .map(|index| message.is_signer(index))
+            .collect();
+
+        TransactionAccountKeys {
+            keys,
+            is_signer,
+            is_writable,
+        }
+    }
+
+    pub fn from_transaction(transaction: &VersionedTransactionWithStatusMeta) -> Self {
+        Self::new(
+            &transaction.transaction.message,
+            &transaction.meta.loaded_addresses,
+        )
+    }
+
+    pub fn keys(&self) -> &[Pubkey] {
+        &self.keys
+    }
+
+    pub fn is_signer(&self, index: usize) -> bool {
+        self.is_signer.get(index).copied().unwrap_or(false)
+    }
+
+    pub fn is_writable(&self, index: usize) -> bool {
+        self.is_writable.get(index).copied().unwrap_or(false)
+    }
+
+    /// The accounts of `ordered`, in the order the instruction lists them.
+    pub fn instruction_keys(
+        &self,
+        ordered: &OrderedInstruction,
+    ) -> Result<Vec<Pubkey>, BlockbusterError> {
+        ordered
+            .instruction
+            .accounts
+            .iter()
+            .map(|index| {
+                self.keys.get(*index as usize).copied().ok_or(
+                    BlockbusterError::InvalidAccountIndex {
+                        index: *index,
+                        key_count: self.keys.len(),
+                        outer_index: ordered.outer_index,
+                        inner_index: ordered.inner_index,
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+/// An instruction of a program in the `programs` set passed to `order_instructions`, with its
+/// position in the transaction.
+#[derive(Debug, Clone)]
+pub struct OrderedInstruction<'a> {
+    pub program: Pubkey,
+    pub instruction: &'a CompiledInstruction,
+    /// Index of the top-level instruction this instruction is, or was invoked by.
+    pub outer_index: usize,
+    /// Position among the inner instructions of `outer_index`, `None` for top-level instructions.
+    pub inner_index: Option<usize>,
+    /// Invocation stack height, where top-level instructions are 1.  `None` for inner
+    /// instructions from transaction metadata that predates stack heights.
+    pub stack_height: Option<u32>,
+    /// Program that invoked this instruction, `None` for top-level instructions.  Without stack
+    /// heights this is the top-level instruction's program, which may be a more distant caller.
+    pub parent_program: Option<Pubkey>,
+    /// Instructions invoked by this instruction, directly or indirectly, in execution order.
+    pub inner_ix: Vec<IxPair<'a>>,
+}
+
+impl<'a> OrderedInstruction<'a> {
+    pub fn ix_pair(&self) -> IxPair<'a> {
+        (self.program, self.instruction)
+    }
+}
+
+/// An inner instruction with its stack height.
+type InnerIxPair<'a> = (IxPair<'a>, Option<u32>);
+
+/// Top-level instructions have a stack height of 1.
+const TRANSACTION_LEVEL_STACK_HEIGHT: u32 = 1;
+
+/// Returns the instructions of `programs`, with each inner instruction of those programs hoisted
+/// ahead of the top-level instruction that invoked it.  Fails if an instruction's program id
+/// index is out of range of `account_keys`, as in a malformed or truncated transaction.
 pub fn order_instructions<'a>(
     programs: &HashSet<Pubkey>,
     account_keys: &[Pubkey],
     message_instructions: &'a [CompiledInstruction],
     meta_inner_instructions: &'a [InnerInstructions],
-) -> VecDeque<(IxPair<'a>, Option<Vec<IxPair<'a>>>)> {
-    let mut ordered_ixs: VecDeque<(IxPair, Option<Vec<IxPair>>)> = VecDeque::new();
+) -> Result<VecDeque<OrderedInstruction<'a>>, BlockbusterError> {
+    let mut ordered_ixs = VecDeque::new();
 
     // Get inner instructions.
     for (outer_instruction_index, message_instruction) in message_instructions.iter().enumerate() {
+        let outer_program_id = program_id(
+            account_keys,
+            message_instruction,
+            outer_instruction_index,
+            None,
+        )?;
         let non_hoisted_inner_instruction = meta_inner_instructions
             .iter()
             .filter_map(|ix| {
                 (ix.index == outer_instruction_index as u8).then_some(&ix.instructions)
             })
             .flatten()
-            .map(|inner_ix| {
+            .enumerate()
+            .map(|(inner_index, inner_ix)| {
                 let cix = &inner_ix.instruction;
-                (account_keys[cix.program_id_index as usize], cix)
+                let pid = program_id(
+                    account_keys,
+                    cix,
+                    outer_instruction_index,
+                    Some(inner_index),
+                )?;
+                // Some sources, e.g. Plerkle, report missing stack heights as 0.
+                let stack_height = inner_ix
+                    .stack_height
+                    .filter(|&height| height > TRANSACTION_LEVEL_STACK_HEIGHT);
+                Ok(((pid, cix), stack_height))
             })
-            .collect::<Vec<IxPair>>();
+            .collect::<Result<Vec<InnerIxPair>, BlockbusterError>>()?;
 
-        let hoisted = hoist_known_programs(programs, &non_hoisted_inner_instruction);
+        let hoisted = hoist_known_programs(
+            programs,
+            outer_instruction_index,
+            outer_program_id,
+            &non_hoisted_inner_instruction,
+        );
         ordered_ixs.extend(hoisted);
 
-        if let Some(outer_program_id) =
-            account_keys.get(message_instruction.program_id_index as usize)
-        {
-            if programs.contains(outer_program_id) {
-                ordered_ixs.push_back((
-                    (*outer_program_id, message_instruction),
-                    Some(non_hoisted_inner_instruction),
-                ));
-            }
-        } else {
-            eprintln!("outer program id deserialization error");
+        if programs.contains(&outer_program_id) {
+            ordered_ixs.push_back(OrderedInstruction {
+                program: outer_program_id,
+                instruction: message_instruction,
+                outer_index: outer_instruction_index,
+                inner_index: None,
+                stack_height: Some(TRANSACTION_LEVEL_STACK_HEIGHT),
+                parent_program: None,
+                inner_ix: non_hoisted_inner_instruction
+                    .into_iter()
+                    .map(|(ix_pair, _)| ix_pair)
+                    .collect(),
+            });
         }
     }
-    ordered_ixs
+    Ok(ordered_ixs)
+}
+
+fn program_id(
+    account_keys: &[Pubkey],
+    instruction: &CompiledInstruction,
+    outer_index: usize,
+    inner_index: Option<usize>,
+) -> Result<Pubkey, BlockbusterError> {
+    account_keys
+        .get(instruction.program_id_index as usize)
+        .copied()
+        .ok_or(BlockbusterError::InvalidProgramIdIndex {
+            index: instruction.program_id_index,
+            key_count: account_keys.len(),
+            outer_index,
+            inner_index,
+        })
 }
 
+/// Resolves the account keys of `message`, including those loaded from address lookup tables,
+/// and orders its instructions as `order_instructions` does.
+pub fn order_versioned_instructions<'a>(
+    programs: &HashSet<Pubkey>,
+    message: &'a VersionedMessage,
+    meta: &'a TransactionStatusMeta,
+) -> Result<(TransactionAccountKeys, VecDeque<OrderedInstruction<'a>>), BlockbusterError> {
+    let account_keys = TransactionAccountKeys::new(message, &meta.loaded_addresses);
+    let ordered_ixs = order_instructions(
+        programs,
+        account_keys.keys(),
+        message.instructions(),
+        meta.inner_instructions.as_deref().unwrap_or_default(),
+    )?;
+    Ok((account_keys, ordered_ixs))
+}
+
+/// Returns the inner instructions of `programs` in `ix_pairs` with the instructions they
+/// invoked.  Those are the following instructions with a greater stack height, or, when stack
+/// heights are missing, the following instructions up to the next call to the same program.
 fn hoist_known_programs<'a>(
     programs: &HashSet<Pubkey>,
-    ix_pairs: &[IxPair<'a>],
-) -> Vec<(IxPair<'a>, Option<Vec<IxPair<'a>>>)> {
+    outer_index: usize,
+    outer_program_id: Pubkey,
+    ix_pairs: &[InnerIxPair<'a>],
+) -> Vec<OrderedInstruction<'a>> {
+    let has_stack_heights = ix_pairs.iter().all(|(_, height)| height.is_some());
+
     ix_pairs
         .iter()
         .enumerate()
-        .filter(|&(_index, &(pid, _ci))| programs.contains(&pid))
-        .map(|(index, &(pid, ci))| {
-            let inner_copy = ix_pairs
-                .iter()
-                .skip(index + 1)
-                .take_while(|&&(inner_pid, _)| inner_pid != pid)
-                .cloned()
-                .collect::<Vec<IxPair<'a>>>();
-            ((pid, ci), Some(inner_copy))
+        .filter(|&(_index, &((pid, _ci), _height))| programs.contains(&pid))
+        .map(|(index, &((pid, ci), stack_height))| {
+            let following = ix_pairs.iter().skip(index + 1);
+            let (inner_copy, parent_program) = match stack_height {
+                Some(height) if has_stack_heights => {
+                    let inner_copy = following
+                        .take_while(|(_, inner_height)| *inner_height > Some(height))
+                        .map(|&(ix_pair, _)| ix_pair)
+                        .collect();
+                    let parent_program = ix_pairs[..index]
+                        .iter()
+                        .rev()
+                        .find(|(_, caller_height)| *caller_height == Some(height - 1))
+                        .map_or(outer_program_id, |&((caller, _), _)| caller);
+                    (inner_copy, parent_program)
+                }
+                _ => {
+                    let inner_copy = following
+                        .take_while(|&&((inner_pid, _), _)| inner_pid != pid)
+                        .map(|&(ix_pair, _)| ix_pair)
+                        .collect();
+                    (inner_copy, outer_program_id)
+                }
+            };
+            OrderedInstruction {
+                program: pid,
+                instruction: ci,
+                outer_index,
+                inner_index: Some(index),
+                stack_height,
+                parent_program: Some(parent_program),
+                inner_ix: inner_copy,
+            }
         })
         .collect()
 }
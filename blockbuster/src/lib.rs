@@ -1,7 +1,12 @@
+pub mod digital_asset;
 pub mod error;
 pub mod instruction;
+pub mod parsed_programs;
+pub mod parser_registry;
 pub mod program_handler;
 pub mod programs;
+#[cfg(feature = "serde")]
+mod serialize;
 
 pub use mpl_core;
 pub use mpl_token_metadata as token_metadata;
@@ -1,9 +1,83 @@
-pub enum Program {
-    Bubblegum {
-        parser: bubblegum::BubblegumParser,
-        instruction_result: BubblegumInstruction,
-        account_result: (),
+use crate::{
+    error::BlockbusterError,
+    instruction::InstructionBundle,
+    program_handler::{ParseResult, ProgramParser},
+    programs::{
+        account_closure::AccountClosureParser, bubblegum::BubblegumParser,
+        mpl_core_program::MplCoreParser, token_account::TokenAccountParser,
+        token_extensions::Token2022AccountParser, token_inscriptions::TokenInscriptionParser,
+        token_metadata::TokenMetadataParser,
     },
+};
+use solana_sdk::pubkey::Pubkey;
+
+/// Statically dispatched set of the parsers shipped with blockbuster.
+pub enum Program {
+    AccountClosure(AccountClosureParser),
+    Bubblegum(BubblegumParser),
+    MplCore(MplCoreParser),
+    TokenAccount(TokenAccountParser),
+    TokenExtensions(Token2022AccountParser),
+    TokenInscription(TokenInscriptionParser),
+    TokenMetadata(TokenMetadataParser),
+}
+
+impl Program {
+    pub fn all() -> Vec<Program> {
+        vec![
+            Program::AccountClosure(AccountClosureParser),
+            Program::Bubblegum(BubblegumParser),
+            Program::MplCore(MplCoreParser),
+            Program::TokenAccount(TokenAccountParser),
+            Program::TokenExtensions(Token2022AccountParser),
+            Program::TokenInscription(TokenInscriptionParser),
+            Program::TokenMetadata(TokenMetadataParser),
+        ]
+    }
+
+    /// Returns the program whose parser matches `key`, if any.
+    pub fn from_key(key: &Pubkey) -> Option<Program> {
+        Self::all()
+            .into_iter()
+            .find(|program| program.key_match(key))
+    }
+
+    fn parser(&self) -> &dyn ProgramParser {
+        match self {
+            Program::AccountClosure(parser) => parser,
+            Program::Bubblegum(parser) => parser,
+            Program::MplCore(parser) => parser,
+            Program::TokenAccount(parser) => parser,
+            Program::TokenExtensions(parser) => parser,
+            Program::TokenInscription(parser) => parser,
+            Program::TokenMetadata(parser) => parser,
+        }
+    }
 }
 
-impl ProgramParser for Program {}
+impl ProgramParser for Program {
+    fn key(&self) -> Pubkey {
+        self.parser().key()
+    }
+    fn key_match(&self, key: &Pubkey) -> bool {
+        self.parser().key_match(key)
+    }
+    fn handles_instructions(&self) -> bool {
+        self.parser().handles_instructions()
+    }
+    fn handles_account_updates(&self) -> bool {
+        self.parser().handles_account_updates()
+    }
+    fn handle_account(
+        &self,
+        account_data: &[u8],
+    ) -> Result<Box<dyn ParseResult>, BlockbusterError> {
+        self.parser().handle_account(account_data)
+    }
+    fn handle_instruction(
+        &self,
+        bundle: &InstructionBundle,
+    ) -> Result<Box<dyn ParseResult>, BlockbusterError> {
+        self.parser().handle_instruction(bundle)
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{
+    error::BlockbusterError,
+    instruction::{order_versioned_instructions, InstructionBundle},
+    program_handler::{ParseResult, ProgramParser},
+    programs::{
+        bubblegum::BubblegumParser, mpl_core_program::MplCoreParser,
+        token_account::TokenAccountParser, token_extensions::Token2022AccountParser,
+        token_inscriptions::TokenInscriptionParser, token_metadata::TokenMetadataParser,
+    },
+};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use solana_sdk::{clock::UnixTimestamp, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::{VersionedConfirmedBlock, VersionedTransactionWithStatusMeta};
+use std::collections::{HashMap, HashSet};
+
+/// Options for `ParserRegistry::parse_transaction`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TransactionParseOptions {
+    /// Parse transactions that failed.  Their instructions had no effect on chain, so they are
+    /// skipped by default.
+    pub include_failed: bool,
+}
+
+/// An instruction parsed by `ParserRegistry::parse_transaction`, with its position in the
+/// transaction.  See `instruction::OrderedInstruction` for the position fields.
+pub struct ParsedInstruction {
+    pub program: Pubkey,
+    pub outer_index: usize,
+    pub inner_index: Option<usize>,
+    pub stack_height: Option<u32>,
+    pub parent_program: Option<Pubkey>,
+    pub result: Result<Box<dyn ParseResult>, BlockbusterError>,
+}
+
+/// The instructions of one transaction parsed by `ParserRegistry::parse_block`, with the
+/// transaction's position in the block.
+pub struct ParsedTransaction {
+    pub slot: u64,
+    pub parent_slot: u64,
+    pub block_time: Option<UnixTimestamp>,
+    /// Index of the transaction in the block.
+    pub transaction_index: usize,
+    pub signature: Signature,
+    /// Parsed instructions, or the error that kept the transaction from being ordered.
+    pub instructions: Result<Vec<ParsedInstruction>, BlockbusterError>,
+}
+
+/// Dispatches account updates and instructions to the `ProgramParser` registered for a program.
+///
+/// `ParserRegistry::with_default_parsers()` registers every parser shipped with blockbuster.  Use
+/// `ParserRegistry::new()` and `register` to build a registry with a custom set of parsers.
+#[derive(Default)]
+pub struct ParserRegistry {
+    parsers: HashMap<Pubkey, Box<dyn ProgramParser>>,
+}
+
+impl ParserRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        ParserRegistry {
+            parsers: HashMap::new(),
+        }
+    }
+
+    /// Creates a registry containing all of the parsers shipped with blockbuster.
+    pub fn with_default_parsers() -> Self {
+        let mut registry = Self::new();
+        registry.register(Box::new(BubblegumParser));
+        registry.register(Box::new(TokenMetadataParser));
+        registry.register(Box::new(TokenAccountParser));
+        registry.register(Box::new(Token2022AccountParser));
+        registry.register(Box::new(MplCoreParser));
+        registry.register(Box::new(TokenInscriptionParser));
+        registry
+    }
+
+    /// Registers a parser under its `key()`, returning any parser previously registered for
+    /// that program.
+    pub fn register(&mut self, parser: Box<dyn ProgramParser>) -> Option<Box<dyn ProgramParser>> {
+        self.parsers.insert(parser.key(), parser)
+    }
+
+    /// Returns the parser for `program`, falling back to `key_match` for parsers that accept
+    /// more than one program id.
+    pub fn get(&self, program: &Pubkey) -> Option<&dyn ProgramParser> {
+        self.parsers
+            .get(program)
+            .or_else(|| {
+                self.parsers
+                    .values()
+                    .find(|parser| parser.key_match(program))
+            })
+            .map(|parser| parser.as_ref())
+    }
+
+    /// Programs whose parsers handle instructions.  This is the set expected by
+    /// `instruction::order_instructions`.
+    pub fn instruction_programs(&self) -> HashSet<Pubkey> {
+        self.parsers
+            .iter()
+            .filter(|(_, parser)| parser.handles_instructions())
+            .map(|(key, _)| *key)
+            .collect()
+    }
+
+    /// Programs whose parsers handle account updates.
+    pub fn account_programs(&self) -> HashSet<Pubkey> {
+        self.parsers
+            .iter()
+            .filter(|(_, parser)| parser.handles_account_updates())
+            .map(|(key, _)| *key)
+            .collect()
+    }
+
+    /// Parses account data owned by `owner`.  Returns `Ok(None)` if no registered parser handles
+    /// account updates for `owner`.
+    pub fn parse_account(
+        &self,
+        owner: &Pubkey,
+        account_data: &[u8],
+    ) -> Result<Option<Box<dyn ParseResult>>, BlockbusterError> {
+        match self.get(owner) {
+            Some(parser) if parser.handles_account_updates() => {
+                parser.handle_account(account_data).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Parses the instruction in `bundle` using the parser registered for `bundle.program`.
+    /// Returns `Ok(None)` if no registered parser handles instructions for that program.
+    pub fn parse_instruction(
+        &self,
+        bundle: &InstructionBundle,
+    ) -> Result<Option<Box<dyn ParseResult>>, BlockbusterError> {
+        match self.get(&bundle.program) {
+            Some(parser) if parser.handles_instructions() => {
+                parser.handle_instruction(bundle).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Parses every instruction of `transaction` handled by a registered parser, in the order of
+    /// `instruction::order_instructions`.  Instructions that fail to parse are returned with
+    /// their error, so one bad instruction does not hide the rest of the transaction.  Fails if
+    /// the transaction itself is malformed.
+    pub fn parse_transaction(
+        &self,
+        transaction: &VersionedTransactionWithStatusMeta,
+        slot: u64,
+        options: TransactionParseOptions,
+    ) -> Result<Vec<ParsedInstruction>, BlockbusterError> {
+        if transaction.meta.status.is_err() && !options.include_failed {
+            return Ok(Vec::new());
+        }
+
+        let txn_id = transaction
+            .transaction
+            .signatures
+            .first()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        let (account_keys, ordered_ixs) = order_versioned_instructions(
+            &self.instruction_programs(),
+            &transaction.transaction.message,
+            &transaction.meta,
+        )?;
+
+        Ok(ordered_ixs
+            .into_iter()
+            .filter_map(|ordered| {
+                let result = match account_keys.instruction_keys(&ordered) {
+                    Ok(keys) => {
+                        let bundle = InstructionBundle {
+                            txn_id: &txn_id,
+                            program: ordered.program,
+                            instruction: Some(ordered.instruction),
+                            inner_ix: Some(&ordered.inner_ix),
+                            keys: &keys,
+                            slot,
+                        };
+                        self.parse_instruction(&bundle).transpose()?
+                    }
+                    Err(err) => Err(err),
+                };
+                Some(ParsedInstruction {
+                    program: ordered.program,
+                    outer_index: ordered.outer_index,
+                    inner_index: ordered.inner_index,
+                    stack_height: ordered.stack_height,
+                    parent_program: ordered.parent_program,
+                    result,
+                })
+            })
+            .collect())
+    }
+
+    /// Parses every transaction of `block` at `slot` with `parse_transaction`.  Transactions
+    /// with no parsed instructions are left out, and a malformed transaction is returned with its
+    /// error rather than failing the block.  With the `rayon` feature, transactions are parsed in
+    /// parallel; results are in block order either way.
+    pub fn parse_block(
+        &self,
+        block: &VersionedConfirmedBlock,
+        slot: u64,
+        options: TransactionParseOptions,
+    ) -> Vec<ParsedTransaction> {
+        let parse =
+            |(transaction_index, transaction): (usize, &VersionedTransactionWithStatusMeta)| {
+                let instructions = self.parse_transaction(transaction, slot, options);
+                if matches!(&instructions, Ok(instructions) if instructions.is_empty()) {
+                    return None;
+                }
+                Some(ParsedTransaction {
+                    slot,
+                    parent_slot: block.parent_slot,
+                    block_time: block.block_time,
+                    transaction_index,
+                    signature: transaction
+                        .transaction
+                        .signatures
+                        .first()
+                        .copied()
+                        .unwrap_or_default(),
+                    instructions,
+                })
+            };
+
+        #[cfg(feature = "rayon")]
+        let transactions = block.transactions.par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let transactions = block.transactions.iter();
+
+        transactions.enumerate().filter_map(parse).collect()
+    }
+}
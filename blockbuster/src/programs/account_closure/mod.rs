@@ -1,20 +1,23 @@
 use crate::{
     error::BlockbusterError,
-    program_handler::{ParseResult, ProgramParser},
+    program_handler::{NotUsed, ParseResult, ProgramParser},
     programs::ProgramParseResult,
 };
 use solana_sdk::{pubkey::Pubkey, pubkeys};
 
-use plerkle_serialization::AccountInfo;
-
 pubkeys!(solana_program_id, "11111111111111111111111111111111");
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ClosedAccountInfo {
-    pub pubkey: Vec<u8>,
-    pub owner: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+    pub pubkey: Pubkey,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+    pub owner: Pubkey,
 }
 
-#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AccountClosureData {
     ClosedAccountInfo(ClosedAccountInfo),
     EmptyAccount,
@@ -32,8 +35,31 @@ impl ParseResult for AccountClosureData {
     }
 }
 
+/// Detects closed accounts, i.e. account updates with zero lamports.  Closure applies to accounts
+/// owned by any program (including the System Program), so this parser works from the account's
+/// pubkey, owner and lamports rather than its data.
 pub struct AccountClosureParser;
 
+impl AccountClosureParser {
+    pub fn handle_account_closure(
+        &self,
+        pubkey: &Pubkey,
+        owner: &Pubkey,
+        lamports: u64,
+    ) -> Result<Box<(dyn ParseResult + 'static)>, BlockbusterError> {
+        let account_closure_data = if lamports == 0 {
+            AccountClosureData::ClosedAccountInfo(ClosedAccountInfo {
+                pubkey: *pubkey,
+                owner: *owner,
+            })
+        } else {
+            AccountClosureData::EmptyAccount
+        };
+
+        Ok(Box::new(account_closure_data))
+    }
+}
+
 impl ProgramParser for AccountClosureParser {
     fn key(&self) -> Pubkey {
         solana_program_id()
@@ -42,8 +68,9 @@ impl ProgramParser for AccountClosureParser {
         key == &solana_program_id()
     }
 
+    // Account data alone is not enough to detect a closure, see `handle_account_closure`.
     fn handles_account_updates(&self) -> bool {
-        true
+        false
     }
 
     fn handles_instructions(&self) -> bool {
@@ -52,22 +79,8 @@ impl ProgramParser for AccountClosureParser {
 
     fn handle_account(
         &self,
-        account_info: &AccountInfo,
+        _account_data: &[u8],
     ) -> Result<Box<(dyn ParseResult + 'static)>, BlockbusterError> {
-        let account_data: ClosedAccountInfo = match (account_info.pubkey(), account_info.owner()) {
-            (Some(pubkey), Some(owner)) => ClosedAccountInfo {
-                pubkey: pubkey.0.to_vec(),
-                owner: owner.0.to_vec(),
-            },
-            _ => return Ok(Box::new(AccountClosureData::EmptyAccount)),
-        };
-
-        if account_info.lamports() == 0 {
-            Ok(Box::new(AccountClosureData::ClosedAccountInfo(
-                account_data,
-            )))
-        } else {
-            Ok(Box::new(AccountClosureData::EmptyAccount))
-        }
+        Ok(Box::new(NotUsed::new()))
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
     error::BlockbusterError,
-    instruction::InstructionBundle,
+    instruction::{get_key, get_optional_key, InstructionBundle},
     program_handler::{NotUsed, ParseResult, ProgramParser},
     programs::ProgramParseResult,
 };
@@ -9,9 +9,21 @@ use log::warn;
 use mpl_bubblegum::{
     get_instruction_type,
     instructions::{
-        UnverifyCreatorInstructionArgs, UpdateMetadataInstructionArgs, VerifyCreatorInstructionArgs,
+        BurnInstructionArgs, BurnV2InstructionArgs, CreateTreeConfigInstructionArgs,
+        CreateTreeConfigV2InstructionArgs, DelegateAndFreezeV2InstructionArgs,
+        DelegateInstructionArgs, DelegateV2InstructionArgs, FreezeV2InstructionArgs,
+        MintV2InstructionArgs, RedeemInstructionArgs, SetCollectionV2InstructionArgs,
+        SetDecompressibleStateInstructionArgs, SetNonTransferableV2InstructionArgs,
+        ThawAndRevokeV2InstructionArgs, ThawV2InstructionArgs, TransferInstructionArgs,
+        TransferV2InstructionArgs, UnverifyCreatorInstructionArgs,
+        UnverifyCreatorV2InstructionArgs, UpdateAssetDataV2InstructionArgs,
+        UpdateMetadataInstructionArgs, UpdateMetadataV2InstructionArgs,
+        VerifyCreatorInstructionArgs, VerifyCreatorV2InstructionArgs,
+    },
+    types::{
+        AssetDataSchema, BubblegumEventType, DecompressibleState, MetadataArgs, MetadataArgsV2,
+        UpdateArgs,
     },
-    types::{BubblegumEventType, MetadataArgs, UpdateArgs},
 };
 pub use mpl_bubblegum::{
     types::{LeafSchema, UseMethod},
@@ -19,39 +31,373 @@ pub use mpl_bubblegum::{
 };
 use solana_sdk::pubkey::Pubkey;
 
+// `SetTreeDelegate` is not mapped by `mpl_bubblegum::get_instruction_type`.
+const SET_TREE_DELEGATE_DISCRIMINATOR: [u8; 8] = [253, 118, 66, 37, 190, 49, 154, 102];
+
+/// Leaf fields passed as instruction arguments by Bubblegum instructions that modify an existing
+/// leaf.  Fields that are not part of a given instruction's arguments are `None`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LeafArgs {
+    pub root: [u8; 32],
+    /// `None` for instructions that pass the full metadata instead of its hash.
+    pub data_hash: Option<[u8; 32]>,
+    /// `None` for instructions that pass the full metadata instead of its hash.
+    pub creator_hash: Option<[u8; 32]>,
+    pub collection_hash: Option<[u8; 32]>,
+    pub asset_data_hash: Option<[u8; 32]>,
+    pub flags: Option<u8>,
+    pub nonce: u64,
+    pub index: u32,
+}
+
 #[derive(Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Payload {
     Unknown,
     MintV1 {
         args: MetadataArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
         authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_creator_or_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
         tree_id: Pubkey,
     },
     Decompress {
         args: MetadataArgs,
     },
     CancelRedeem {
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::hash"))]
         root: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        voucher: Pubkey,
     },
     CreatorVerification {
         metadata: MetadataArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
         creator: Pubkey,
         verify: bool,
     },
     CollectionVerification {
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
         collection: Pubkey,
         verify: bool,
     },
     UpdateMetadata {
         current_metadata: MetadataArgs,
         update_args: UpdateArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+    },
+    Transfer {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        new_leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+    },
+    Burn {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+    },
+    Delegate {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        previous_leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        new_leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+    },
+    Redeem {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        voucher: Pubkey,
+    },
+    /// Emitted for both `CreateTree` and `CreateTreeV2`.
+    CreateTree {
+        max_depth: u32,
+        max_buffer_size: u32,
+        public: Option<bool>,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        payer: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_creator: Pubkey,
+    },
+    SetTreeDelegate {
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_creator: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        new_tree_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+    },
+    SetDecompressibleState {
+        decompressible_state: DecompressibleState,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_creator: Pubkey,
+    },
+    CollectV2 {
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        destination: Pubkey,
+    },
+    MintV2 {
+        args: MetadataArgsV2,
+        asset_data: Option<Vec<u8>>,
+        asset_data_schema: Option<AssetDataSchema>,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_creator_or_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+    },
+    TransferV2 {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        new_leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::option_pubkey")
+        )]
+        collection: Option<Pubkey>,
+    },
+    BurnV2 {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::option_pubkey")
+        )]
+        collection: Option<Pubkey>,
+    },
+    DelegateV2 {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        previous_leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        new_leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+    },
+    DelegateAndFreezeV2 {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        previous_leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        new_leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+    },
+    FreezeV2 {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::option_pubkey")
+        )]
+        collection: Option<Pubkey>,
+    },
+    ThawV2 {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::option_pubkey")
+        )]
+        collection: Option<Pubkey>,
+    },
+    ThawAndRevokeV2 {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+    },
+    SetCollectionV2 {
+        leaf: LeafArgs,
+        metadata: MetadataArgsV2,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        new_collection_authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::option_pubkey")
+        )]
+        collection: Option<Pubkey>,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::option_pubkey")
+        )]
+        new_collection: Option<Pubkey>,
+    },
+    UpdateMetadataV2 {
+        leaf: LeafArgs,
+        current_metadata: MetadataArgsV2,
+        update_args: UpdateArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
         tree_id: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::option_pubkey")
+        )]
+        collection: Option<Pubkey>,
+    },
+    UpdateAssetDataV2 {
+        leaf: LeafArgs,
+        new_asset_data: Option<Vec<u8>>,
+        new_asset_data_schema: Option<AssetDataSchema>,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::option_pubkey")
+        )]
+        collection: Option<Pubkey>,
+    },
+    SetNonTransferableV2 {
+        leaf: LeafArgs,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        authority: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        collection: Pubkey,
+    },
+    CreatorVerificationV2 {
+        leaf: LeafArgs,
+        metadata: MetadataArgsV2,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        creator: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_owner: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        leaf_delegate: Pubkey,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+        tree_id: Pubkey,
+        verify: bool,
     },
 }
-//TODO add more of the parsing here to minimize program transformer code
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BubblegumInstruction {
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::instruction_name")
+    )]
     pub instruction: InstructionName,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::change_log_event")
+    )]
     pub tree_update: Option<spl_account_compression::events::ChangeLogEventV1>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::leaf_schema_event")
+    )]
     pub leaf_update: Option<LeafSchemaEvent>,
     pub payload: Option<Payload>,
 }
@@ -114,10 +460,14 @@ impl ProgramParser for BubblegumParser {
             keys,
             ..
         } = bundle;
-        let outer_ix_data = match instruction {
+        let outer_ix_data: &[u8] = match instruction {
             Some(cix) => cix.data.as_ref(),
             _ => return Err(BlockbusterError::DeserializationError),
         };
+        // `get_instruction_type` reads an 8-byte discriminator and panics on shorter data.
+        if outer_ix_data.len() < 8 {
+            return Err(BlockbusterError::InstructionParsingError);
+        }
         let ix_type = get_instruction_type(outer_ix_data);
         let mut b_inst = BubblegumInstruction::new(ix_type);
         if let Some(ixs) = inner_ix {
@@ -180,48 +530,122 @@ impl ProgramParser for BubblegumParser {
             }
         }
 
-        if outer_ix_data.len() >= 8 {
-            let ix_data = &outer_ix_data[8..];
-            if !ix_data.is_empty() {
-                match b_inst.instruction {
-                    InstructionName::MintV1 => {
-                        b_inst.payload = Some(build_mint_v1_payload(keys, ix_data, false)?);
-                    }
+        let ix_data = &outer_ix_data[8..];
+        if ix_data.is_empty() {
+            match b_inst.instruction {
+                InstructionName::CollectV2 => {
+                    b_inst.payload = Some(build_collect_v2_payload(keys)?);
+                }
+                InstructionName::Unknown
+                    if outer_ix_data[..8] == SET_TREE_DELEGATE_DISCRIMINATOR =>
+                {
+                    b_inst.payload = Some(build_set_tree_delegate_payload(keys)?);
+                }
+                _ => {}
+            };
+        } else {
+            match b_inst.instruction {
+                InstructionName::MintV1 => {
+                    b_inst.payload = Some(build_mint_v1_payload(keys, ix_data, false)?);
+                }
 
-                    InstructionName::MintToCollectionV1 => {
-                        b_inst.payload = Some(build_mint_v1_payload(keys, ix_data, true)?);
-                    }
-                    InstructionName::DecompressV1 => {
-                        let args: MetadataArgs = MetadataArgs::try_from_slice(ix_data)?;
-                        b_inst.payload = Some(Payload::Decompress { args });
-                    }
-                    InstructionName::CancelRedeem => {
-                        let slice: [u8; 32] = ix_data
-                            .try_into()
-                            .map_err(|_e| BlockbusterError::InstructionParsingError)?;
-                        let root = Pubkey::new_from_array(slice);
-                        b_inst.payload = Some(Payload::CancelRedeem { root });
-                    }
-                    InstructionName::VerifyCreator => {
-                        b_inst.payload =
-                            Some(build_creator_verification_payload(keys, ix_data, true)?);
-                    }
-                    InstructionName::UnverifyCreator => {
-                        b_inst.payload =
-                            Some(build_creator_verification_payload(keys, ix_data, false)?);
-                    }
-                    InstructionName::VerifyCollection | InstructionName::SetAndVerifyCollection => {
-                        b_inst.payload = Some(build_collection_verification_payload(keys, true)?);
-                    }
-                    InstructionName::UnverifyCollection => {
-                        b_inst.payload = Some(build_collection_verification_payload(keys, false)?);
-                    }
-                    InstructionName::UpdateMetadata => {
-                        b_inst.payload = Some(build_update_metadata_payload(keys, ix_data)?);
-                    }
-                    _ => {}
-                };
-            }
+                InstructionName::MintToCollectionV1 => {
+                    b_inst.payload = Some(build_mint_v1_payload(keys, ix_data, true)?);
+                }
+                InstructionName::DecompressV1 => {
+                    let args: MetadataArgs = MetadataArgs::try_from_slice(ix_data)?;
+                    b_inst.payload = Some(Payload::Decompress { args });
+                }
+                InstructionName::CancelRedeem => {
+                    let slice: [u8; 32] = ix_data
+                        .try_into()
+                        .map_err(|_e| BlockbusterError::InstructionParsingError)?;
+                    let root = Pubkey::new_from_array(slice);
+                    b_inst.payload = Some(build_cancel_redeem_payload(keys, root)?);
+                }
+                InstructionName::VerifyCreator => {
+                    b_inst.payload = Some(build_creator_verification_payload(keys, ix_data, true)?);
+                }
+                InstructionName::UnverifyCreator => {
+                    b_inst.payload =
+                        Some(build_creator_verification_payload(keys, ix_data, false)?);
+                }
+                InstructionName::VerifyCollection | InstructionName::SetAndVerifyCollection => {
+                    b_inst.payload = Some(build_collection_verification_payload(keys, true)?);
+                }
+                InstructionName::UnverifyCollection => {
+                    b_inst.payload = Some(build_collection_verification_payload(keys, false)?);
+                }
+                InstructionName::UpdateMetadata => {
+                    b_inst.payload = Some(build_update_metadata_payload(keys, ix_data)?);
+                }
+                InstructionName::Transfer => {
+                    b_inst.payload = Some(build_transfer_payload(keys, ix_data)?);
+                }
+                InstructionName::Burn => {
+                    b_inst.payload = Some(build_burn_payload(keys, ix_data)?);
+                }
+                InstructionName::Delegate => {
+                    b_inst.payload = Some(build_delegate_payload(keys, ix_data)?);
+                }
+                InstructionName::Redeem => {
+                    b_inst.payload = Some(build_redeem_payload(keys, ix_data)?);
+                }
+                InstructionName::CreateTree => {
+                    b_inst.payload = Some(build_create_tree_payload(keys, ix_data, false)?);
+                }
+                InstructionName::CreateTreeV2 => {
+                    b_inst.payload = Some(build_create_tree_payload(keys, ix_data, true)?);
+                }
+                InstructionName::SetDecompressibleState => {
+                    b_inst.payload = Some(build_set_decompressible_state_payload(keys, ix_data)?);
+                }
+                InstructionName::MintV2 => {
+                    b_inst.payload = Some(build_mint_v2_payload(keys, ix_data)?);
+                }
+                InstructionName::TransferV2 => {
+                    b_inst.payload = Some(build_transfer_v2_payload(keys, ix_data)?);
+                }
+                InstructionName::BurnV2 => {
+                    b_inst.payload = Some(build_burn_v2_payload(keys, ix_data)?);
+                }
+                InstructionName::DelegateV2 => {
+                    b_inst.payload = Some(build_delegate_v2_payload(keys, ix_data, false)?);
+                }
+                InstructionName::DelegateAndFreezeV2 => {
+                    b_inst.payload = Some(build_delegate_v2_payload(keys, ix_data, true)?);
+                }
+                InstructionName::FreezeV2 => {
+                    b_inst.payload = Some(build_freeze_v2_payload(keys, ix_data, true)?);
+                }
+                InstructionName::ThawV2 => {
+                    b_inst.payload = Some(build_freeze_v2_payload(keys, ix_data, false)?);
+                }
+                InstructionName::ThawAndRevokeV2 => {
+                    b_inst.payload = Some(build_thaw_and_revoke_v2_payload(keys, ix_data)?);
+                }
+                InstructionName::SetCollectionV2 => {
+                    b_inst.payload = Some(build_set_collection_v2_payload(keys, ix_data)?);
+                }
+                InstructionName::UpdateMetadataV2 => {
+                    b_inst.payload = Some(build_update_metadata_v2_payload(keys, ix_data)?);
+                }
+                InstructionName::UpdateAssetDataV2 => {
+                    b_inst.payload = Some(build_update_asset_data_v2_payload(keys, ix_data)?);
+                }
+                InstructionName::SetNonTransferableV2 => {
+                    b_inst.payload = Some(build_set_non_transferable_v2_payload(keys, ix_data)?);
+                }
+                InstructionName::VerifyCreatorV2 => {
+                    b_inst.payload =
+                        Some(build_creator_verification_v2_payload(keys, ix_data, true)?);
+                }
+                InstructionName::UnverifyCreatorV2 => {
+                    b_inst.payload =
+                        Some(build_creator_verification_v2_payload(keys, ix_data, false)?);
+                }
+                _ => {}
+            };
         }
 
         Ok(Box::new(b_inst))
@@ -274,9 +698,7 @@ fn build_creator_verification_payload(
         UnverifyCreatorInstructionArgs::try_from_slice(ix_data)?.metadata
     };
 
-    let creator = *keys
-        .get(5)
-        .ok_or(BlockbusterError::InstructionParsingError)?;
+    let creator = get_key(keys, 5)?;
 
     Ok(Payload::CreatorVerification {
         metadata,
@@ -292,9 +714,7 @@ fn build_collection_verification_payload(
     keys: &[Pubkey],
     verify: bool,
 ) -> Result<Payload, BlockbusterError> {
-    let collection = *keys
-        .get(8)
-        .ok_or(BlockbusterError::InstructionParsingError)?;
+    let collection = get_key(keys, 8)?;
     Ok(Payload::CollectionVerification { collection, verify })
 }
 
@@ -312,17 +732,16 @@ fn build_mint_v1_payload(
         }
     }
 
-    let authority = *keys
-        .first()
-        .ok_or(BlockbusterError::InstructionParsingError)?;
+    let authority = get_key(keys, 0)?;
 
-    let tree_id = *keys
-        .get(3)
-        .ok_or(BlockbusterError::InstructionParsingError)?;
+    let tree_id = get_key(keys, 3)?;
+
+    let tree_creator_or_delegate = get_key(keys, 5)?;
 
     Ok(Payload::MintV1 {
         args,
         authority,
+        tree_creator_or_delegate,
         tree_id,
     })
 }
@@ -335,9 +754,7 @@ fn build_update_metadata_payload(
 ) -> Result<Payload, BlockbusterError> {
     let args = UpdateMetadataInstructionArgs::try_from_slice(ix_data)?;
 
-    let tree_id = *keys
-        .get(8)
-        .ok_or(BlockbusterError::InstructionParsingError)?;
+    let tree_id = get_key(keys, 8)?;
 
     Ok(Payload::UpdateMetadata {
         current_metadata: args.current_metadata,
@@ -345,3 +762,557 @@ fn build_update_metadata_payload(
         tree_id,
     })
 }
+
+// See Bubblegum for offsets and positions:
+// https://github.com/metaplex-foundation/mpl-bubblegum/blob/main/programs/bubblegum/README.md
+fn build_transfer_payload(keys: &[Pubkey], ix_data: &[u8]) -> Result<Payload, BlockbusterError> {
+    let args = TransferInstructionArgs::try_from_slice(ix_data)?;
+
+    Ok(Payload::Transfer {
+        leaf: LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: None,
+            asset_data_hash: None,
+            flags: None,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        tree_authority: get_key(keys, 0)?,
+        leaf_owner: get_key(keys, 1)?,
+        leaf_delegate: get_key(keys, 2)?,
+        new_leaf_owner: get_key(keys, 3)?,
+        tree_id: get_key(keys, 4)?,
+    })
+}
+
+fn build_burn_payload(keys: &[Pubkey], ix_data: &[u8]) -> Result<Payload, BlockbusterError> {
+    let args = BurnInstructionArgs::try_from_slice(ix_data)?;
+
+    Ok(Payload::Burn {
+        leaf: LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: None,
+            asset_data_hash: None,
+            flags: None,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        tree_authority: get_key(keys, 0)?,
+        leaf_owner: get_key(keys, 1)?,
+        leaf_delegate: get_key(keys, 2)?,
+        tree_id: get_key(keys, 3)?,
+    })
+}
+
+fn build_delegate_payload(keys: &[Pubkey], ix_data: &[u8]) -> Result<Payload, BlockbusterError> {
+    let args = DelegateInstructionArgs::try_from_slice(ix_data)?;
+
+    Ok(Payload::Delegate {
+        leaf: LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: None,
+            asset_data_hash: None,
+            flags: None,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        tree_authority: get_key(keys, 0)?,
+        leaf_owner: get_key(keys, 1)?,
+        previous_leaf_delegate: get_key(keys, 2)?,
+        new_leaf_delegate: get_key(keys, 3)?,
+        tree_id: get_key(keys, 4)?,
+    })
+}
+
+fn build_redeem_payload(keys: &[Pubkey], ix_data: &[u8]) -> Result<Payload, BlockbusterError> {
+    let args = RedeemInstructionArgs::try_from_slice(ix_data)?;
+
+    Ok(Payload::Redeem {
+        leaf: LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: None,
+            asset_data_hash: None,
+            flags: None,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        tree_authority: get_key(keys, 0)?,
+        leaf_owner: get_key(keys, 1)?,
+        leaf_delegate: get_key(keys, 2)?,
+        tree_id: get_key(keys, 3)?,
+        voucher: get_key(keys, 4)?,
+    })
+}
+
+fn build_cancel_redeem_payload(keys: &[Pubkey], root: Pubkey) -> Result<Payload, BlockbusterError> {
+    Ok(Payload::CancelRedeem {
+        root,
+        tree_authority: get_key(keys, 0)?,
+        leaf_owner: get_key(keys, 1)?,
+        tree_id: get_key(keys, 2)?,
+        voucher: get_key(keys, 3)?,
+    })
+}
+
+// `CreateTree` and `CreateTreeV2` share the same accounts and argument layout, but the tree
+// creator is optional in `CreateTreeV2`.
+fn build_create_tree_payload(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+    v2: bool,
+) -> Result<Payload, BlockbusterError> {
+    let payer = get_key(keys, 2)?;
+    let (max_depth, max_buffer_size, public, tree_creator) = if v2 {
+        let args = CreateTreeConfigV2InstructionArgs::try_from_slice(ix_data)?;
+        let tree_creator = get_optional_key(keys, 3, &ID).unwrap_or(payer);
+        (
+            args.max_depth,
+            args.max_buffer_size,
+            args.public,
+            tree_creator,
+        )
+    } else {
+        let args = CreateTreeConfigInstructionArgs::try_from_slice(ix_data)?;
+        let tree_creator = get_key(keys, 3)?;
+        (
+            args.max_depth,
+            args.max_buffer_size,
+            args.public,
+            tree_creator,
+        )
+    };
+
+    Ok(Payload::CreateTree {
+        max_depth,
+        max_buffer_size,
+        public,
+        tree_authority: get_key(keys, 0)?,
+        tree_id: get_key(keys, 1)?,
+        payer,
+        tree_creator,
+    })
+}
+
+fn build_set_tree_delegate_payload(keys: &[Pubkey]) -> Result<Payload, BlockbusterError> {
+    Ok(Payload::SetTreeDelegate {
+        tree_authority: get_key(keys, 0)?,
+        tree_creator: get_key(keys, 1)?,
+        new_tree_delegate: get_key(keys, 2)?,
+        tree_id: get_key(keys, 3)?,
+    })
+}
+
+fn build_set_decompressible_state_payload(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+) -> Result<Payload, BlockbusterError> {
+    let args = SetDecompressibleStateInstructionArgs::try_from_slice(ix_data)?;
+
+    Ok(Payload::SetDecompressibleState {
+        decompressible_state: args.decompressable_state,
+        tree_authority: get_key(keys, 0)?,
+        tree_creator: get_key(keys, 1)?,
+    })
+}
+
+fn build_collect_v2_payload(keys: &[Pubkey]) -> Result<Payload, BlockbusterError> {
+    Ok(Payload::CollectV2 {
+        tree_authority: get_key(keys, 0)?,
+        destination: get_key(keys, 1)?,
+    })
+}
+
+// See Bubblegum for offsets and positions:
+// https://github.com/metaplex-foundation/mpl-bubblegum/blob/main/programs/bubblegum/README.md
+fn build_mint_v2_payload(keys: &[Pubkey], ix_data: &[u8]) -> Result<Payload, BlockbusterError> {
+    let args = MintV2InstructionArgs::try_from_slice(ix_data)?;
+
+    let authority = get_key(keys, 0)?;
+    let tree_creator_or_delegate = get_optional_key(keys, 2, &ID).unwrap_or(get_key(keys, 1)?);
+    let leaf_owner = get_key(keys, 4)?;
+    let leaf_delegate = get_optional_key(keys, 5, &ID).unwrap_or(leaf_owner);
+    let tree_id = get_key(keys, 6)?;
+
+    Ok(Payload::MintV2 {
+        args: args.metadata,
+        asset_data: args.asset_data,
+        asset_data_schema: args.asset_data_schema,
+        authority,
+        tree_creator_or_delegate,
+        tree_id,
+        leaf_owner,
+        leaf_delegate,
+    })
+}
+
+fn build_transfer_v2_payload(keys: &[Pubkey], ix_data: &[u8]) -> Result<Payload, BlockbusterError> {
+    let args = TransferV2InstructionArgs::try_from_slice(ix_data)?;
+
+    let payer = get_key(keys, 1)?;
+    let leaf_owner = get_key(keys, 3)?;
+
+    Ok(Payload::TransferV2 {
+        leaf: LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: None,
+            asset_data_hash: args.asset_data_hash,
+            flags: args.flags,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        authority: get_optional_key(keys, 2, &ID).unwrap_or(payer),
+        leaf_owner,
+        leaf_delegate: get_optional_key(keys, 4, &ID).unwrap_or(leaf_owner),
+        new_leaf_owner: get_key(keys, 5)?,
+        tree_id: get_key(keys, 6)?,
+        collection: get_optional_key(keys, 7, &ID),
+    })
+}
+
+fn build_burn_v2_payload(keys: &[Pubkey], ix_data: &[u8]) -> Result<Payload, BlockbusterError> {
+    let args = BurnV2InstructionArgs::try_from_slice(ix_data)?;
+
+    let payer = get_key(keys, 1)?;
+    let leaf_owner = get_key(keys, 3)?;
+
+    Ok(Payload::BurnV2 {
+        leaf: LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: None,
+            asset_data_hash: args.asset_data_hash,
+            flags: args.flags,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        authority: get_optional_key(keys, 2, &ID).unwrap_or(payer),
+        leaf_owner,
+        leaf_delegate: get_optional_key(keys, 4, &ID).unwrap_or(leaf_owner),
+        tree_id: get_key(keys, 5)?,
+        collection: get_optional_key(keys, 6, &ID),
+    })
+}
+
+// `DelegateV2` and `DelegateAndFreezeV2` share the same accounts and argument layout.
+fn build_delegate_v2_payload(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+    freeze: bool,
+) -> Result<Payload, BlockbusterError> {
+    let leaf = if freeze {
+        let args = DelegateAndFreezeV2InstructionArgs::try_from_slice(ix_data)?;
+        LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: args.collection_hash,
+            asset_data_hash: args.asset_data_hash,
+            flags: args.flags,
+            nonce: args.nonce,
+            index: args.index,
+        }
+    } else {
+        let args = DelegateV2InstructionArgs::try_from_slice(ix_data)?;
+        LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: args.collection_hash,
+            asset_data_hash: args.asset_data_hash,
+            flags: args.flags,
+            nonce: args.nonce,
+            index: args.index,
+        }
+    };
+
+    let payer = get_key(keys, 1)?;
+    let leaf_owner = get_optional_key(keys, 2, &ID).unwrap_or(payer);
+    let previous_leaf_delegate = get_optional_key(keys, 3, &ID).unwrap_or(leaf_owner);
+    let new_leaf_delegate = get_key(keys, 4)?;
+    let tree_id = get_key(keys, 5)?;
+
+    if freeze {
+        Ok(Payload::DelegateAndFreezeV2 {
+            leaf,
+            leaf_owner,
+            previous_leaf_delegate,
+            new_leaf_delegate,
+            tree_id,
+        })
+    } else {
+        Ok(Payload::DelegateV2 {
+            leaf,
+            leaf_owner,
+            previous_leaf_delegate,
+            new_leaf_delegate,
+            tree_id,
+        })
+    }
+}
+
+// `FreezeV2` and `ThawV2` share the same accounts and argument layout.
+fn build_freeze_v2_payload(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+    freeze: bool,
+) -> Result<Payload, BlockbusterError> {
+    let leaf = if freeze {
+        let args = FreezeV2InstructionArgs::try_from_slice(ix_data)?;
+        LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: None,
+            asset_data_hash: args.asset_data_hash,
+            flags: args.flags,
+            nonce: args.nonce,
+            index: args.index,
+        }
+    } else {
+        let args = ThawV2InstructionArgs::try_from_slice(ix_data)?;
+        LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: None,
+            asset_data_hash: args.asset_data_hash,
+            flags: args.flags,
+            nonce: args.nonce,
+            index: args.index,
+        }
+    };
+
+    let payer = get_key(keys, 1)?;
+    let authority = get_optional_key(keys, 2, &ID).unwrap_or(payer);
+    let leaf_owner = get_key(keys, 3)?;
+    let leaf_delegate = get_key(keys, 4)?;
+    let tree_id = get_key(keys, 5)?;
+    let collection = get_optional_key(keys, 6, &ID);
+
+    if freeze {
+        Ok(Payload::FreezeV2 {
+            leaf,
+            authority,
+            leaf_owner,
+            leaf_delegate,
+            tree_id,
+            collection,
+        })
+    } else {
+        Ok(Payload::ThawV2 {
+            leaf,
+            authority,
+            leaf_owner,
+            leaf_delegate,
+            tree_id,
+            collection,
+        })
+    }
+}
+
+fn build_thaw_and_revoke_v2_payload(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+) -> Result<Payload, BlockbusterError> {
+    let args = ThawAndRevokeV2InstructionArgs::try_from_slice(ix_data)?;
+
+    let payer = get_key(keys, 1)?;
+
+    Ok(Payload::ThawAndRevokeV2 {
+        leaf: LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: args.collection_hash,
+            asset_data_hash: args.asset_data_hash,
+            flags: args.flags,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        leaf_delegate: get_optional_key(keys, 2, &ID).unwrap_or(payer),
+        leaf_owner: get_key(keys, 3)?,
+        tree_id: get_key(keys, 4)?,
+    })
+}
+
+fn build_set_collection_v2_payload(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+) -> Result<Payload, BlockbusterError> {
+    let args = SetCollectionV2InstructionArgs::try_from_slice(ix_data)?;
+
+    let payer = get_key(keys, 1)?;
+    let authority = get_optional_key(keys, 2, &ID).unwrap_or(payer);
+    let leaf_owner = get_key(keys, 4)?;
+
+    Ok(Payload::SetCollectionV2 {
+        leaf: LeafArgs {
+            root: args.root,
+            data_hash: None,
+            creator_hash: None,
+            collection_hash: None,
+            asset_data_hash: args.asset_data_hash,
+            flags: args.flags,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        metadata: args.metadata,
+        authority,
+        new_collection_authority: get_optional_key(keys, 3, &ID).unwrap_or(authority),
+        leaf_owner,
+        leaf_delegate: get_optional_key(keys, 5, &ID).unwrap_or(leaf_owner),
+        tree_id: get_key(keys, 6)?,
+        collection: get_optional_key(keys, 7, &ID),
+        new_collection: get_optional_key(keys, 8, &ID),
+    })
+}
+
+fn build_update_metadata_v2_payload(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+) -> Result<Payload, BlockbusterError> {
+    let args = UpdateMetadataV2InstructionArgs::try_from_slice(ix_data)?;
+
+    let payer = get_key(keys, 1)?;
+    let leaf_owner = get_key(keys, 3)?;
+
+    Ok(Payload::UpdateMetadataV2 {
+        leaf: LeafArgs {
+            root: args.root,
+            data_hash: None,
+            creator_hash: None,
+            collection_hash: None,
+            asset_data_hash: args.asset_data_hash,
+            flags: args.flags,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        current_metadata: args.current_metadata,
+        update_args: args.update_args,
+        authority: get_optional_key(keys, 2, &ID).unwrap_or(payer),
+        leaf_owner,
+        leaf_delegate: get_optional_key(keys, 4, &ID).unwrap_or(leaf_owner),
+        tree_id: get_key(keys, 5)?,
+        collection: get_optional_key(keys, 6, &ID),
+    })
+}
+
+fn build_update_asset_data_v2_payload(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+) -> Result<Payload, BlockbusterError> {
+    let args = UpdateAssetDataV2InstructionArgs::try_from_slice(ix_data)?;
+
+    let payer = get_key(keys, 1)?;
+    let leaf_owner = get_key(keys, 3)?;
+
+    Ok(Payload::UpdateAssetDataV2 {
+        leaf: LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: None,
+            asset_data_hash: args.previous_asset_data_hash,
+            flags: args.flags,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        new_asset_data: args.new_asset_data,
+        new_asset_data_schema: args.new_asset_data_schema,
+        authority: get_optional_key(keys, 2, &ID).unwrap_or(payer),
+        leaf_owner,
+        leaf_delegate: get_optional_key(keys, 4, &ID).unwrap_or(leaf_owner),
+        tree_id: get_key(keys, 5)?,
+        collection: get_optional_key(keys, 6, &ID),
+    })
+}
+
+fn build_set_non_transferable_v2_payload(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+) -> Result<Payload, BlockbusterError> {
+    let args = SetNonTransferableV2InstructionArgs::try_from_slice(ix_data)?;
+
+    let payer = get_key(keys, 1)?;
+    let leaf_owner = get_key(keys, 3)?;
+
+    Ok(Payload::SetNonTransferableV2 {
+        leaf: LeafArgs {
+            root: args.root,
+            data_hash: Some(args.data_hash),
+            creator_hash: Some(args.creator_hash),
+            collection_hash: None,
+            asset_data_hash: args.asset_data_hash,
+            flags: args.flags,
+            nonce: args.nonce,
+            index: args.index,
+        },
+        authority: get_optional_key(keys, 2, &ID).unwrap_or(payer),
+        leaf_owner,
+        leaf_delegate: get_optional_key(keys, 4, &ID).unwrap_or(leaf_owner),
+        tree_id: get_key(keys, 5)?,
+        collection: get_key(keys, 6)?,
+    })
+}
+
+fn build_creator_verification_v2_payload(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+    verify: bool,
+) -> Result<Payload, BlockbusterError> {
+    let (leaf, metadata) = if verify {
+        let args = VerifyCreatorV2InstructionArgs::try_from_slice(ix_data)?;
+        (
+            LeafArgs {
+                root: args.root,
+                data_hash: None,
+                creator_hash: None,
+                collection_hash: None,
+                asset_data_hash: args.asset_data_hash,
+                flags: args.flags,
+                nonce: args.nonce,
+                index: args.index,
+            },
+            args.metadata,
+        )
+    } else {
+        let args = UnverifyCreatorV2InstructionArgs::try_from_slice(ix_data)?;
+        (
+            LeafArgs {
+                root: args.root,
+                data_hash: None,
+                creator_hash: None,
+                collection_hash: None,
+                asset_data_hash: args.asset_data_hash,
+                flags: args.flags,
+                nonce: args.nonce,
+                index: args.index,
+            },
+            args.metadata,
+        )
+    };
+
+    let payer = get_key(keys, 1)?;
+    let leaf_owner = get_key(keys, 3)?;
+
+    Ok(Payload::CreatorVerificationV2 {
+        leaf,
+        metadata,
+        creator: get_optional_key(keys, 2, &ID).unwrap_or(payer),
+        leaf_owner,
+        leaf_delegate: get_optional_key(keys, 4, &ID).unwrap_or(leaf_owner),
+        tree_id: get_key(keys, 5)?,
+        verify,
+    })
+}
@@ -1,10 +1,12 @@
+use account_closure::AccountClosureData;
 use bubblegum::BubblegumInstruction;
-use mpl_core_program::MplCoreAccountState;
-use token_account::TokenProgramAccount;
-use token_extensions::TokenExtensionsProgramAccount;
+use mpl_core_program::{instruction::MplCoreInstruction, MplCoreAccountState};
+use token_account::{instruction::TokenProgramInstruction, TokenProgramAccount};
+use token_extensions::{instruction::TokenExtensionsInstruction, TokenExtensionsProgramAccount};
 use token_inscriptions::TokenInscriptionAccount;
-use token_metadata::TokenMetadataAccountState;
+use token_metadata::{instruction::TokenMetadataInstruction, TokenMetadataAccountState};
 
+pub mod account_closure;
 pub mod bubblegum;
 pub mod mpl_core_program;
 pub mod token_account;
@@ -26,12 +28,18 @@ pub mod token_metadata;
 //
 // Candy Machine V2 (`mpl-candy-machine`) parsing was removed at the same time as V3 because even
 // though it did not depend on the `mpl-candy-machine` crate, it was also not being used by DAS.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ProgramParseResult<'a> {
+    AccountClosure(&'a AccountClosureData),
     Bubblegum(&'a BubblegumInstruction),
     MplCore(&'a MplCoreAccountState),
+    MplCoreInstruction(&'a MplCoreInstruction),
     TokenMetadata(&'a TokenMetadataAccountState),
+    TokenMetadataInstruction(&'a TokenMetadataInstruction),
     TokenProgramAccount(&'a TokenProgramAccount),
+    TokenProgramInstruction(&'a TokenProgramInstruction),
     TokenExtensionsProgramAccount(&'a TokenExtensionsProgramAccount),
+    TokenExtensionsInstruction(&'a TokenExtensionsInstruction),
     TokenInscriptionAccount(&'a TokenInscriptionAccount),
     Unknown,
 }
@@ -0,0 +1,73 @@
+use crate::error::BlockbusterError;
+use borsh::BorshSerialize;
+use mpl_core::{
+    accounts::{BaseAssetV1, HashedAssetV1},
+    types::{CompressionProof, HashablePluginSchema, HashedAssetSchema, Key, PluginType},
+    IndexableAsset, IndexablePluginSchemaV1,
+};
+use solana_sdk::keccak;
+
+fn hash<T: BorshSerialize>(data: &T) -> Result<[u8; 32], BlockbusterError> {
+    Ok(keccak::hash(&data.try_to_vec()?).to_bytes())
+}
+
+fn base_asset(proof: &CompressionProof) -> BaseAssetV1 {
+    BaseAssetV1 {
+        key: Key::AssetV1,
+        owner: proof.owner,
+        update_authority: proof.update_authority.clone(),
+        name: proof.name.clone(),
+        uri: proof.uri.clone(),
+        seq: Some(proof.seq),
+    }
+}
+
+fn sorted_plugins(proof: &CompressionProof) -> Vec<&HashablePluginSchema> {
+    let mut plugins: Vec<_> = proof.plugins.iter().collect();
+    plugins.sort_by_key(|plugin| plugin.index);
+    plugins
+}
+
+/// Computes the hash MPL Core stores in a `HashedAssetV1` account for the asset described by
+/// `proof`: the keccak hash of a `HashedAssetSchema` made of the base asset hash and the hash of
+/// each plugin, ordered by plugin index.
+pub fn hash_compression_proof(proof: &CompressionProof) -> Result<[u8; 32], BlockbusterError> {
+    let plugin_hashes = sorted_plugins(proof)
+        .into_iter()
+        .map(hash)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    hash(&HashedAssetSchema {
+        asset_hash: hash(&base_asset(proof))?,
+        plugin_hashes,
+    })
+}
+
+/// Rebuilds the `IndexableAsset` for a hashed (compressed) Core asset from a `CompressionProof`,
+/// e.g. one emitted to the noop program or passed as an instruction arg in the same transaction.
+/// Returns `InvalidCompressionProof` if the proof does not match the hash stored on chain.
+///
+/// Compressed plugins have no account data, so plugin offsets are reported as 0.
+pub fn rebuild_hashed_asset(
+    hashed_asset: &HashedAssetV1,
+    proof: &CompressionProof,
+) -> Result<IndexableAsset, BlockbusterError> {
+    if hash_compression_proof(proof)? != hashed_asset.hash {
+        return Err(BlockbusterError::InvalidCompressionProof);
+    }
+
+    let mut indexable_asset = IndexableAsset::from_asset(base_asset(proof), proof.seq);
+    for plugin in sorted_plugins(proof) {
+        indexable_asset.plugins.insert(
+            PluginType::from(&plugin.plugin),
+            IndexablePluginSchemaV1 {
+                index: plugin.index,
+                offset: 0,
+                authority: plugin.authority.clone(),
+                data: plugin.plugin.clone(),
+            },
+        );
+    }
+
+    Ok(indexable_asset)
+}
@@ -0,0 +1,540 @@
+use crate::{
+    error::BlockbusterError,
+    instruction::{get_key, get_optional_key},
+    program_handler::ParseResult,
+    programs::ProgramParseResult,
+};
+use borsh::BorshDeserialize;
+use mpl_core::{
+    instructions::{
+        AddCollectionPluginV1, AddCollectionPluginV1InstructionArgs, AddPluginV1,
+        AddPluginV1InstructionArgs, ApproveCollectionPluginAuthorityV1,
+        ApproveCollectionPluginAuthorityV1InstructionArgs, ApprovePluginAuthorityV1,
+        ApprovePluginAuthorityV1InstructionArgs, BurnCollectionV1, BurnCollectionV1InstructionArgs,
+        BurnV1, BurnV1InstructionArgs, CompressV1, CreateCollectionV1,
+        CreateCollectionV1InstructionArgs, CreateCollectionV2, CreateCollectionV2InstructionArgs,
+        CreateV1, CreateV1InstructionArgs, CreateV2, CreateV2InstructionArgs, DecompressV1,
+        DecompressV1InstructionArgs, RemoveCollectionPluginV1,
+        RemoveCollectionPluginV1InstructionArgs, RemovePluginV1, RemovePluginV1InstructionArgs,
+        RevokeCollectionPluginAuthorityV1, RevokeCollectionPluginAuthorityV1InstructionArgs,
+        RevokePluginAuthorityV1, RevokePluginAuthorityV1InstructionArgs, TransferV1,
+        TransferV1InstructionArgs, UpdateCollectionPluginV1,
+        UpdateCollectionPluginV1InstructionArgs, UpdateCollectionV1,
+        UpdateCollectionV1InstructionArgs, UpdatePluginV1, UpdatePluginV1InstructionArgs, UpdateV1,
+        UpdateV1InstructionArgs, UpdateV2, UpdateV2InstructionArgs,
+    },
+    types::CompressionProof,
+    ID,
+};
+use solana_sdk::pubkey::Pubkey;
+
+const CREATE_V1_DISCRIMINATOR: u8 = 0;
+const CREATE_COLLECTION_V1_DISCRIMINATOR: u8 = 1;
+const ADD_PLUGIN_V1_DISCRIMINATOR: u8 = 2;
+const ADD_COLLECTION_PLUGIN_V1_DISCRIMINATOR: u8 = 3;
+const REMOVE_PLUGIN_V1_DISCRIMINATOR: u8 = 4;
+const REMOVE_COLLECTION_PLUGIN_V1_DISCRIMINATOR: u8 = 5;
+const UPDATE_PLUGIN_V1_DISCRIMINATOR: u8 = 6;
+const UPDATE_COLLECTION_PLUGIN_V1_DISCRIMINATOR: u8 = 7;
+const APPROVE_PLUGIN_AUTHORITY_V1_DISCRIMINATOR: u8 = 8;
+const APPROVE_COLLECTION_PLUGIN_AUTHORITY_V1_DISCRIMINATOR: u8 = 9;
+const REVOKE_PLUGIN_AUTHORITY_V1_DISCRIMINATOR: u8 = 10;
+const REVOKE_COLLECTION_PLUGIN_AUTHORITY_V1_DISCRIMINATOR: u8 = 11;
+const BURN_V1_DISCRIMINATOR: u8 = 12;
+const BURN_COLLECTION_V1_DISCRIMINATOR: u8 = 13;
+const TRANSFER_V1_DISCRIMINATOR: u8 = 14;
+const UPDATE_V1_DISCRIMINATOR: u8 = 15;
+const UPDATE_COLLECTION_V1_DISCRIMINATOR: u8 = 16;
+const COMPRESS_V1_DISCRIMINATOR: u8 = 17;
+const DECOMPRESS_V1_DISCRIMINATOR: u8 = 18;
+const CREATE_V2_DISCRIMINATOR: u8 = 20;
+const CREATE_COLLECTION_V2_DISCRIMINATOR: u8 = 21;
+const UPDATE_V2_DISCRIMINATOR: u8 = 30;
+
+/// Decoded MPL Core instructions.  Accounts use the `mpl_core::instructions` account structs and
+/// args use the matching `*InstructionArgs` types.  Optional accounts that were omitted (passed as
+/// the MPL Core program id) are `None`.
+#[allow(clippy::large_enum_variant)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MplCoreInstructionData {
+    AddCollectionPluginV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: AddCollectionPluginV1,
+        args: AddCollectionPluginV1InstructionArgs,
+    },
+    AddPluginV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: AddPluginV1,
+        args: AddPluginV1InstructionArgs,
+    },
+    ApproveCollectionPluginAuthorityV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: ApproveCollectionPluginAuthorityV1,
+        args: ApproveCollectionPluginAuthorityV1InstructionArgs,
+    },
+    ApprovePluginAuthorityV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: ApprovePluginAuthorityV1,
+        args: ApprovePluginAuthorityV1InstructionArgs,
+    },
+    BurnCollectionV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: BurnCollectionV1,
+        args: BurnCollectionV1InstructionArgs,
+    },
+    BurnV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: BurnV1,
+        args: BurnV1InstructionArgs,
+    },
+    CompressV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: CompressV1,
+    },
+    CreateCollectionV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: CreateCollectionV1,
+        args: CreateCollectionV1InstructionArgs,
+    },
+    CreateCollectionV2 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: CreateCollectionV2,
+        args: CreateCollectionV2InstructionArgs,
+    },
+    CreateV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: CreateV1,
+        args: CreateV1InstructionArgs,
+    },
+    CreateV2 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: CreateV2,
+        args: CreateV2InstructionArgs,
+    },
+    DecompressV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: DecompressV1,
+        args: DecompressV1InstructionArgs,
+    },
+    RemoveCollectionPluginV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: RemoveCollectionPluginV1,
+        args: RemoveCollectionPluginV1InstructionArgs,
+    },
+    RemovePluginV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: RemovePluginV1,
+        args: RemovePluginV1InstructionArgs,
+    },
+    RevokeCollectionPluginAuthorityV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: RevokeCollectionPluginAuthorityV1,
+        args: RevokeCollectionPluginAuthorityV1InstructionArgs,
+    },
+    RevokePluginAuthorityV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: RevokePluginAuthorityV1,
+        args: RevokePluginAuthorityV1InstructionArgs,
+    },
+    TransferV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: TransferV1,
+        args: TransferV1InstructionArgs,
+    },
+    UpdateCollectionPluginV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: UpdateCollectionPluginV1,
+        args: UpdateCollectionPluginV1InstructionArgs,
+    },
+    UpdateCollectionV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: UpdateCollectionV1,
+        args: UpdateCollectionV1InstructionArgs,
+    },
+    UpdatePluginV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: UpdatePluginV1,
+        args: UpdatePluginV1InstructionArgs,
+    },
+    UpdateV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: UpdateV1,
+        args: UpdateV1InstructionArgs,
+    },
+    UpdateV2 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: UpdateV2,
+        args: UpdateV2InstructionArgs,
+    },
+    /// An instruction that blockbuster does not decode yet.
+    Unknown,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MplCoreInstruction {
+    pub discriminator: u8,
+    pub data: MplCoreInstructionData,
+    /// `CompressionProof` emitted to a noop program by this instruction, if any.
+    pub compression_event: Option<CompressionProof>,
+}
+
+impl MplCoreInstruction {
+    /// Returns the `CompressionProof` describing the asset touched by this instruction, taken from
+    /// the noop event if there is one and otherwise from the instruction args.
+    pub fn compression_proof(&self) -> Option<&CompressionProof> {
+        self.compression_event.as_ref().or(match &self.data {
+            MplCoreInstructionData::BurnV1 { args, .. } => args.compression_proof.as_ref(),
+            MplCoreInstructionData::DecompressV1 { args, .. } => Some(&args.compression_proof),
+            MplCoreInstructionData::TransferV1 { args, .. } => args.compression_proof.as_ref(),
+            _ => None,
+        })
+    }
+}
+
+impl ParseResult for MplCoreInstruction {
+    fn result(&self) -> &Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+    fn result_type(&self) -> ProgramParseResult {
+        ProgramParseResult::MplCoreInstruction(self)
+    }
+}
+
+/// Decodes MPL Core instruction data.  `keys` are the instruction's accounts, in order.
+pub fn parse_instruction(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+) -> Result<MplCoreInstruction, BlockbusterError> {
+    let (discriminator, args) = ix_data
+        .split_first()
+        .ok_or(BlockbusterError::InstructionParsingError)?;
+
+    // See MPL Core for account positions:
+    // https://github.com/metaplex-foundation/mpl-core/blob/main/programs/mpl-core/src/instruction.rs
+    let data = match *discriminator {
+        ADD_COLLECTION_PLUGIN_V1_DISCRIMINATOR => MplCoreInstructionData::AddCollectionPluginV1 {
+            accounts: AddCollectionPluginV1 {
+                collection: get_key(keys, 0)?,
+                payer: get_key(keys, 1)?,
+                authority: get_optional_key(keys, 2, &ID),
+                system_program: get_key(keys, 3)?,
+                log_wrapper: get_optional_key(keys, 4, &ID),
+            },
+            args: AddCollectionPluginV1InstructionArgs::try_from_slice(args)?,
+        },
+        ADD_PLUGIN_V1_DISCRIMINATOR => MplCoreInstructionData::AddPluginV1 {
+            accounts: AddPluginV1 {
+                asset: get_key(keys, 0)?,
+                collection: get_optional_key(keys, 1, &ID),
+                payer: get_key(keys, 2)?,
+                authority: get_optional_key(keys, 3, &ID),
+                system_program: get_key(keys, 4)?,
+                log_wrapper: get_optional_key(keys, 5, &ID),
+            },
+            args: AddPluginV1InstructionArgs::try_from_slice(args)?,
+        },
+        APPROVE_COLLECTION_PLUGIN_AUTHORITY_V1_DISCRIMINATOR => {
+            MplCoreInstructionData::ApproveCollectionPluginAuthorityV1 {
+                accounts: ApproveCollectionPluginAuthorityV1 {
+                    collection: get_key(keys, 0)?,
+                    payer: get_key(keys, 1)?,
+                    authority: get_optional_key(keys, 2, &ID),
+                    system_program: get_key(keys, 3)?,
+                    log_wrapper: get_optional_key(keys, 4, &ID),
+                },
+                args: ApproveCollectionPluginAuthorityV1InstructionArgs::try_from_slice(args)?,
+            }
+        }
+        APPROVE_PLUGIN_AUTHORITY_V1_DISCRIMINATOR => {
+            MplCoreInstructionData::ApprovePluginAuthorityV1 {
+                accounts: ApprovePluginAuthorityV1 {
+                    asset: get_key(keys, 0)?,
+                    collection: get_optional_key(keys, 1, &ID),
+                    payer: get_key(keys, 2)?,
+                    authority: get_optional_key(keys, 3, &ID),
+                    system_program: get_key(keys, 4)?,
+                    log_wrapper: get_optional_key(keys, 5, &ID),
+                },
+                args: ApprovePluginAuthorityV1InstructionArgs::try_from_slice(args)?,
+            }
+        }
+        BURN_COLLECTION_V1_DISCRIMINATOR => MplCoreInstructionData::BurnCollectionV1 {
+            accounts: BurnCollectionV1 {
+                collection: get_key(keys, 0)?,
+                payer: get_key(keys, 1)?,
+                authority: get_optional_key(keys, 2, &ID),
+                log_wrapper: get_optional_key(keys, 3, &ID),
+            },
+            args: BurnCollectionV1InstructionArgs::try_from_slice(args)?,
+        },
+        BURN_V1_DISCRIMINATOR => MplCoreInstructionData::BurnV1 {
+            accounts: BurnV1 {
+                asset: get_key(keys, 0)?,
+                collection: get_optional_key(keys, 1, &ID),
+                payer: get_key(keys, 2)?,
+                authority: get_optional_key(keys, 3, &ID),
+                system_program: get_optional_key(keys, 4, &ID),
+                log_wrapper: get_optional_key(keys, 5, &ID),
+            },
+            args: BurnV1InstructionArgs::try_from_slice(args)?,
+        },
+        COMPRESS_V1_DISCRIMINATOR => MplCoreInstructionData::CompressV1 {
+            accounts: CompressV1 {
+                asset: get_key(keys, 0)?,
+                collection: get_optional_key(keys, 1, &ID),
+                payer: get_key(keys, 2)?,
+                authority: get_optional_key(keys, 3, &ID),
+                system_program: get_key(keys, 4)?,
+                log_wrapper: get_optional_key(keys, 5, &ID),
+            },
+        },
+        CREATE_COLLECTION_V1_DISCRIMINATOR => MplCoreInstructionData::CreateCollectionV1 {
+            accounts: CreateCollectionV1 {
+                collection: get_key(keys, 0)?,
+                update_authority: get_optional_key(keys, 1, &ID),
+                payer: get_key(keys, 2)?,
+                system_program: get_key(keys, 3)?,
+            },
+            args: CreateCollectionV1InstructionArgs::try_from_slice(args)?,
+        },
+        CREATE_COLLECTION_V2_DISCRIMINATOR => MplCoreInstructionData::CreateCollectionV2 {
+            accounts: CreateCollectionV2 {
+                collection: get_key(keys, 0)?,
+                update_authority: get_optional_key(keys, 1, &ID),
+                payer: get_key(keys, 2)?,
+                system_program: get_key(keys, 3)?,
+            },
+            args: CreateCollectionV2InstructionArgs::try_from_slice(args)?,
+        },
+        CREATE_V1_DISCRIMINATOR => MplCoreInstructionData::CreateV1 {
+            accounts: CreateV1 {
+                asset: get_key(keys, 0)?,
+                collection: get_optional_key(keys, 1, &ID),
+                authority: get_optional_key(keys, 2, &ID),
+                payer: get_key(keys, 3)?,
+                owner: get_optional_key(keys, 4, &ID),
+                update_authority: get_optional_key(keys, 5, &ID),
+                system_program: get_key(keys, 6)?,
+                log_wrapper: get_optional_key(keys, 7, &ID),
+            },
+            args: CreateV1InstructionArgs::try_from_slice(args)?,
+        },
+        CREATE_V2_DISCRIMINATOR => MplCoreInstructionData::CreateV2 {
+            accounts: CreateV2 {
+                asset: get_key(keys, 0)?,
+                collection: get_optional_key(keys, 1, &ID),
+                authority: get_optional_key(keys, 2, &ID),
+                payer: get_key(keys, 3)?,
+                owner: get_optional_key(keys, 4, &ID),
+                update_authority: get_optional_key(keys, 5, &ID),
+                system_program: get_key(keys, 6)?,
+                log_wrapper: get_optional_key(keys, 7, &ID),
+            },
+            args: CreateV2InstructionArgs::try_from_slice(args)?,
+        },
+        DECOMPRESS_V1_DISCRIMINATOR => MplCoreInstructionData::DecompressV1 {
+            accounts: DecompressV1 {
+                asset: get_key(keys, 0)?,
+                collection: get_optional_key(keys, 1, &ID),
+                payer: get_key(keys, 2)?,
+                authority: get_optional_key(keys, 3, &ID),
+                system_program: get_key(keys, 4)?,
+                log_wrapper: get_optional_key(keys, 5, &ID),
+            },
+            args: DecompressV1InstructionArgs::try_from_slice(args)?,
+        },
+        REMOVE_COLLECTION_PLUGIN_V1_DISCRIMINATOR => {
+            MplCoreInstructionData::RemoveCollectionPluginV1 {
+                accounts: RemoveCollectionPluginV1 {
+                    collection: get_key(keys, 0)?,
+                    payer: get_key(keys, 1)?,
+                    authority: get_optional_key(keys, 2, &ID),
+                    system_program: get_key(keys, 3)?,
+                    log_wrapper: get_optional_key(keys, 4, &ID),
+                },
+                args: RemoveCollectionPluginV1InstructionArgs::try_from_slice(args)?,
+            }
+        }
+        REMOVE_PLUGIN_V1_DISCRIMINATOR => MplCoreInstructionData::RemovePluginV1 {
+            accounts: RemovePluginV1 {
+                asset: get_key(keys, 0)?,
+                collection: get_optional_key(keys, 1, &ID),
+                payer: get_key(keys, 2)?,
+                authority: get_optional_key(keys, 3, &ID),
+                system_program: get_key(keys, 4)?,
+                log_wrapper: get_optional_key(keys, 5, &ID),
+            },
+            args: RemovePluginV1InstructionArgs::try_from_slice(args)?,
+        },
+        REVOKE_COLLECTION_PLUGIN_AUTHORITY_V1_DISCRIMINATOR => {
+            MplCoreInstructionData::RevokeCollectionPluginAuthorityV1 {
+                accounts: RevokeCollectionPluginAuthorityV1 {
+                    collection: get_key(keys, 0)?,
+                    payer: get_key(keys, 1)?,
+                    authority: get_optional_key(keys, 2, &ID),
+                    system_program: get_key(keys, 3)?,
+                    log_wrapper: get_optional_key(keys, 4, &ID),
+                },
+                args: RevokeCollectionPluginAuthorityV1InstructionArgs::try_from_slice(args)?,
+            }
+        }
+        REVOKE_PLUGIN_AUTHORITY_V1_DISCRIMINATOR => {
+            MplCoreInstructionData::RevokePluginAuthorityV1 {
+                accounts: RevokePluginAuthorityV1 {
+                    asset: get_key(keys, 0)?,
+                    collection: get_optional_key(keys, 1, &ID),
+                    payer: get_key(keys, 2)?,
+                    authority: get_optional_key(keys, 3, &ID),
+                    system_program: get_key(keys, 4)?,
+                    log_wrapper: get_optional_key(keys, 5, &ID),
+                },
+                args: RevokePluginAuthorityV1InstructionArgs::try_from_slice(args)?,
+            }
+        }
+        TRANSFER_V1_DISCRIMINATOR => MplCoreInstructionData::TransferV1 {
+            accounts: TransferV1 {
+                asset: get_key(keys, 0)?,
+                collection: get_optional_key(keys, 1, &ID),
+                payer: get_key(keys, 2)?,
+                authority: get_optional_key(keys, 3, &ID),
+                new_owner: get_key(keys, 4)?,
+                system_program: get_optional_key(keys, 5, &ID),
+                log_wrapper: get_optional_key(keys, 6, &ID),
+            },
+            args: TransferV1InstructionArgs::try_from_slice(args)?,
+        },
+        UPDATE_COLLECTION_PLUGIN_V1_DISCRIMINATOR => {
+            MplCoreInstructionData::UpdateCollectionPluginV1 {
+                accounts: UpdateCollectionPluginV1 {
+                    collection: get_key(keys, 0)?,
+                    payer: get_key(keys, 1)?,
+                    authority: get_optional_key(keys, 2, &ID),
+                    system_program: get_key(keys, 3)?,
+                    log_wrapper: get_optional_key(keys, 4, &ID),
+                },
+                args: UpdateCollectionPluginV1InstructionArgs::try_from_slice(args)?,
+            }
+        }
+        UPDATE_COLLECTION_V1_DISCRIMINATOR => MplCoreInstructionData::UpdateCollectionV1 {
+            accounts: UpdateCollectionV1 {
+                collection: get_key(keys, 0)?,
+                payer: get_key(keys, 1)?,
+                authority: get_optional_key(keys, 2, &ID),
+                new_update_authority: get_optional_key(keys, 3, &ID),
+                system_program: get_key(keys, 4)?,
+                log_wrapper: get_optional_key(keys, 5, &ID),
+            },
+            args: UpdateCollectionV1InstructionArgs::try_from_slice(args)?,
+        },
+        UPDATE_PLUGIN_V1_DISCRIMINATOR => MplCoreInstructionData::UpdatePluginV1 {
+            accounts: UpdatePluginV1 {
+                asset: get_key(keys, 0)?,
+                collection: get_optional_key(keys, 1, &ID),
+                payer: get_key(keys, 2)?,
+                authority: get_optional_key(keys, 3, &ID),
+                system_program: get_key(keys, 4)?,
+                log_wrapper: get_optional_key(keys, 5, &ID),
+            },
+            args: UpdatePluginV1InstructionArgs::try_from_slice(args)?,
+        },
+        UPDATE_V1_DISCRIMINATOR => MplCoreInstructionData::UpdateV1 {
+            accounts: UpdateV1 {
+                asset: get_key(keys, 0)?,
+                collection: get_optional_key(keys, 1, &ID),
+                payer: get_key(keys, 2)?,
+                authority: get_optional_key(keys, 3, &ID),
+                system_program: get_key(keys, 4)?,
+                log_wrapper: get_optional_key(keys, 5, &ID),
+            },
+            args: UpdateV1InstructionArgs::try_from_slice(args)?,
+        },
+        UPDATE_V2_DISCRIMINATOR => MplCoreInstructionData::UpdateV2 {
+            accounts: UpdateV2 {
+                asset: get_key(keys, 0)?,
+                collection: get_optional_key(keys, 1, &ID),
+                payer: get_key(keys, 2)?,
+                authority: get_optional_key(keys, 3, &ID),
+                new_collection: get_optional_key(keys, 4, &ID),
+                system_program: get_key(keys, 5)?,
+                log_wrapper: get_optional_key(keys, 6, &ID),
+            },
+            args: UpdateV2InstructionArgs::try_from_slice(args)?,
+        },
+        _ => MplCoreInstructionData::Unknown,
+    };
+
+    Ok(MplCoreInstruction {
+        discriminator: *discriminator,
+        data,
+        compression_event: None,
+    })
+}
@@ -1,22 +1,31 @@
+pub mod compression;
+pub mod instruction;
 use crate::{
     error::BlockbusterError,
+    instruction::InstructionBundle,
     program_handler::{ParseResult, ProgramParser},
     programs::ProgramParseResult,
 };
 use borsh::BorshDeserialize;
-use mpl_core::{types::Key, IndexableAsset};
+use mpl_core::{
+    accounts::HashedAssetV1,
+    types::{CompressionProof, Key},
+    IndexableAsset,
+};
 use solana_sdk::{pubkey::Pubkey, pubkeys};
 
 pubkeys!(mpl_core_id, "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum MplCoreAccountData {
     Asset(IndexableAsset),
     Collection(IndexableAsset),
-    HashedAsset,
+    HashedAsset(HashedAssetV1),
     EmptyAccount,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct MplCoreAccountState {
     pub key: Key,
     pub data: MplCoreAccountData,
@@ -49,7 +58,7 @@ impl ProgramParser for MplCoreParser {
     }
 
     fn handles_instructions(&self) -> bool {
-        false
+        true
     }
 
     fn handle_account(
@@ -78,6 +87,15 @@ impl ProgramParser for MplCoreParser {
                     data: MplCoreAccountData::Collection(indexable_asset),
                 }
             }
+            // Hashed assets only store a hash of the compressed asset, see
+            // `compression::rebuild_hashed_asset` to recover the asset itself.
+            Key::HashedAssetV1 => {
+                let hashed_asset = HashedAssetV1::from_bytes(account_data)?;
+                MplCoreAccountState {
+                    key,
+                    data: MplCoreAccountData::HashedAsset(hashed_asset),
+                }
+            }
             Key::Uninitialized => MplCoreAccountState {
                 key: Key::Uninitialized,
                 data: MplCoreAccountData::EmptyAccount,
@@ -89,4 +107,24 @@ impl ProgramParser for MplCoreParser {
 
         Ok(Box::new(mpl_core_account_state))
     }
+
+    fn handle_instruction(
+        &self,
+        bundle: &InstructionBundle,
+    ) -> Result<Box<(dyn ParseResult + 'static)>, BlockbusterError> {
+        let ix_data = match bundle.instruction {
+            Some(cix) => cix.data.as_ref(),
+            _ => return Err(BlockbusterError::DeserializationError),
+        };
+
+        let mut mpl_core_instruction = instruction::parse_instruction(bundle.keys, ix_data)?;
+        if let Some(ixs) = bundle.inner_ix {
+            mpl_core_instruction.compression_event = ixs
+                .iter()
+                .filter(|(pid, _)| pid == &mpl_noop::id() || pid == &spl_noop::id())
+                .find_map(|(_, cix)| CompressionProof::try_from_slice(&cix.data).ok());
+        }
+
+        Ok(Box::new(mpl_core_instruction))
+    }
 }
@@ -0,0 +1,308 @@
+use crate::{
+    error::BlockbusterError, instruction::get_key, program_handler::ParseResult,
+    programs::ProgramParseResult,
+};
+use solana_sdk::pubkey::Pubkey;
+use spl_token::instruction::TokenInstruction;
+
+/// Accounts of a token program instruction, resolved by role.  Roles that do not apply to an
+/// instruction are `None`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TokenInstructionAccounts {
+    /// Account being initialized, closed, frozen, thawed or having an authority changed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub account: Option<Pubkey>,
+    /// Token account debited, burned from, or delegated/revoked.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub source: Option<Pubkey>,
+    /// Token account credited, or the lamports recipient of `CloseAccount`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub destination: Option<Pubkey>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub mint: Option<Pubkey>,
+    /// Owner, delegate, mint/freeze/close authority or multisig authorizing the instruction.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub authority: Option<Pubkey>,
+    /// Delegate being approved.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub delegate: Option<Pubkey>,
+    /// Multisig signers, or the signers of a multisig being initialized.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::vec_pubkey")
+    )]
+    pub signers: Vec<Pubkey>,
+    /// Token accounts that Token-2022 transfer fees are harvested or withdrawn from.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::vec_pubkey")
+    )]
+    pub token_accounts: Vec<Pubkey>,
+}
+
+/// Defines `$name`, a decoded `TokenInstruction` that does not borrow the instruction data.
+/// `UiAmountToAmount` is the only variant that borrows, so its amount is kept as an owned string
+/// and every other variant, listed exhaustively, is rebuilt with a `'static` lifetime.
+macro_rules! decoded_token_instruction {
+    ($name:ident: $($variant:ident $({ $($field:ident),* })? $(($inner:ident))?),* $(,)?) => {
+        #[derive(Clone, Debug, PartialEq)]
+        enum $name {
+            Owned(TokenInstruction<'static>),
+            UiAmountToAmount(String),
+        }
+
+        impl $name {
+            #[allow(deprecated)]
+            fn new(instruction: TokenInstruction<'_>) -> Self {
+                match instruction {
+                    TokenInstruction::UiAmountToAmount { ui_amount } => {
+                        Self::UiAmountToAmount(ui_amount.to_string())
+                    }
+                    $(TokenInstruction::$variant $({ $($field),* })? $(($inner))? => {
+                        Self::Owned(TokenInstruction::$variant $({ $($field),* })? $(($inner))?)
+                    })*
+                }
+            }
+
+            fn instruction(&self) -> TokenInstruction<'_> {
+                match self {
+                    Self::Owned(instruction) => instruction.clone(),
+                    Self::UiAmountToAmount(ui_amount) => {
+                        TokenInstruction::UiAmountToAmount { ui_amount }
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl crate::serialize::PackedTokenInstruction for $name {
+            fn pack(&self) -> Vec<u8> {
+                self.instruction().pack()
+            }
+        }
+    };
+}
+
+pub(crate) use decoded_token_instruction;
+
+decoded_token_instruction!(DecodedTokenInstruction:
+    InitializeMint { decimals, mint_authority, freeze_authority },
+    InitializeAccount,
+    InitializeMultisig { m },
+    Transfer { amount },
+    Approve { amount },
+    Revoke,
+    SetAuthority { authority_type, new_authority },
+    MintTo { amount },
+    Burn { amount },
+    CloseAccount,
+    FreezeAccount,
+    ThawAccount,
+    TransferChecked { amount, decimals },
+    ApproveChecked { amount, decimals },
+    MintToChecked { amount, decimals },
+    BurnChecked { amount, decimals },
+    InitializeAccount2 { owner },
+    SyncNative,
+    InitializeAccount3 { owner },
+    InitializeMultisig2 { m },
+    InitializeMint2 { decimals, mint_authority, freeze_authority },
+    GetAccountDataSize,
+    InitializeImmutableOwner,
+    AmountToUiAmount { amount },
+);
+
+/// A decoded token program instruction.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TokenProgramInstruction {
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "instruction",
+            serialize_with = "crate::serialize::token_instruction"
+        )
+    )]
+    instruction: DecodedTokenInstruction,
+    pub accounts: TokenInstructionAccounts,
+}
+
+impl TokenProgramInstruction {
+    pub fn instruction(&self) -> TokenInstruction<'_> {
+        self.instruction.instruction()
+    }
+}
+
+impl ParseResult for TokenProgramInstruction {
+    fn result(&self) -> &Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+    fn result_type(&self) -> ProgramParseResult {
+        ProgramParseResult::TokenProgramInstruction(self)
+    }
+}
+
+/// Decodes token program instruction data.  `keys` are the instruction's accounts, in order.
+pub fn parse_instruction(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+) -> Result<TokenProgramInstruction, BlockbusterError> {
+    let instruction =
+        TokenInstruction::unpack(ix_data).map_err(|_| BlockbusterError::InstructionParsingError)?;
+    let accounts = resolve_accounts(&instruction, keys)?;
+
+    Ok(TokenProgramInstruction {
+        instruction: DecodedTokenInstruction::new(instruction),
+        accounts,
+    })
+}
+
+pub(crate) fn get_signers(keys: &[Pubkey], index: usize) -> Vec<Pubkey> {
+    keys.get(index..).unwrap_or_default().to_vec()
+}
+
+// See the token program for account positions:
+// https://github.com/solana-labs/solana-program-library/blob/master/token/program/src/instruction.rs
+fn resolve_accounts(
+    instruction: &TokenInstruction,
+    keys: &[Pubkey],
+) -> Result<TokenInstructionAccounts, BlockbusterError> {
+    let accounts = match instruction {
+        TokenInstruction::InitializeMint { .. }
+        | TokenInstruction::InitializeMint2 { .. }
+        | TokenInstruction::GetAccountDataSize
+        | TokenInstruction::AmountToUiAmount { .. }
+        | TokenInstruction::UiAmountToAmount { .. } => TokenInstructionAccounts {
+            mint: Some(get_key(keys, 0)?),
+            ..Default::default()
+        },
+        TokenInstruction::InitializeAccount => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            mint: Some(get_key(keys, 1)?),
+            authority: Some(get_key(keys, 2)?),
+            ..Default::default()
+        },
+        TokenInstruction::InitializeAccount2 { owner }
+        | TokenInstruction::InitializeAccount3 { owner } => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            mint: Some(get_key(keys, 1)?),
+            authority: Some(*owner),
+            ..Default::default()
+        },
+        TokenInstruction::InitializeMultisig { .. } => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            signers: get_signers(keys, 2),
+            ..Default::default()
+        },
+        TokenInstruction::InitializeMultisig2 { .. } => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            signers: get_signers(keys, 1),
+            ..Default::default()
+        },
+        TokenInstruction::Transfer { .. } => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            destination: Some(get_key(keys, 1)?),
+            authority: Some(get_key(keys, 2)?),
+            signers: get_signers(keys, 3),
+            ..Default::default()
+        },
+        TokenInstruction::Approve { .. } => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            delegate: Some(get_key(keys, 1)?),
+            authority: Some(get_key(keys, 2)?),
+            signers: get_signers(keys, 3),
+            ..Default::default()
+        },
+        TokenInstruction::Revoke => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            authority: Some(get_key(keys, 1)?),
+            signers: get_signers(keys, 2),
+            ..Default::default()
+        },
+        TokenInstruction::SetAuthority { .. } => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            authority: Some(get_key(keys, 1)?),
+            signers: get_signers(keys, 2),
+            ..Default::default()
+        },
+        TokenInstruction::MintTo { .. } | TokenInstruction::MintToChecked { .. } => {
+            TokenInstructionAccounts {
+                mint: Some(get_key(keys, 0)?),
+                destination: Some(get_key(keys, 1)?),
+                authority: Some(get_key(keys, 2)?),
+                signers: get_signers(keys, 3),
+                ..Default::default()
+            }
+        }
+        TokenInstruction::Burn { .. } | TokenInstruction::BurnChecked { .. } => {
+            TokenInstructionAccounts {
+                source: Some(get_key(keys, 0)?),
+                mint: Some(get_key(keys, 1)?),
+                authority: Some(get_key(keys, 2)?),
+                signers: get_signers(keys, 3),
+                ..Default::default()
+            }
+        }
+        TokenInstruction::CloseAccount => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            destination: Some(get_key(keys, 1)?),
+            authority: Some(get_key(keys, 2)?),
+            signers: get_signers(keys, 3),
+            ..Default::default()
+        },
+        TokenInstruction::FreezeAccount | TokenInstruction::ThawAccount => {
+            TokenInstructionAccounts {
+                account: Some(get_key(keys, 0)?),
+                mint: Some(get_key(keys, 1)?),
+                authority: Some(get_key(keys, 2)?),
+                signers: get_signers(keys, 3),
+                ..Default::default()
+            }
+        }
+        TokenInstruction::TransferChecked { .. } => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            mint: Some(get_key(keys, 1)?),
+            destination: Some(get_key(keys, 2)?),
+            authority: Some(get_key(keys, 3)?),
+            signers: get_signers(keys, 4),
+            ..Default::default()
+        },
+        TokenInstruction::ApproveChecked { .. } => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            mint: Some(get_key(keys, 1)?),
+            delegate: Some(get_key(keys, 2)?),
+            authority: Some(get_key(keys, 3)?),
+            signers: get_signers(keys, 4),
+            ..Default::default()
+        },
+        TokenInstruction::SyncNative | TokenInstruction::InitializeImmutableOwner => {
+            TokenInstructionAccounts {
+                account: Some(get_key(keys, 0)?),
+                ..Default::default()
+            }
+        }
+    };
+
+    Ok(accounts)
+}
@@ -1,10 +1,12 @@
+pub mod instruction;
 use crate::{
     error::BlockbusterError,
+    instruction::InstructionBundle,
     program_handler::{ParseResult, ProgramParser},
     programs::ProgramParseResult,
 };
 use solana_sdk::{program_pack::Pack, pubkey::Pubkey, pubkeys};
-use spl_token::state::{Account as TokenAccount, Mint};
+use spl_token::state::{Account as TokenAccount, Mint, Multisig};
 
 pubkeys!(
     token_program_id,
@@ -13,9 +15,29 @@ pubkeys!(
 
 pub struct TokenAccountParser;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TokenProgramAccount {
-    Mint(Mint),
-    TokenAccount(TokenAccount),
+    Mint(
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::spl_token_mint")
+        )]
+        Mint,
+    ),
+    TokenAccount(
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::spl_token_account")
+        )]
+        TokenAccount,
+    ),
+    Multisig(
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::spl_token_multisig")
+        )]
+        Multisig,
+    ),
 }
 
 impl ParseResult for TokenProgramAccount {
@@ -42,7 +64,7 @@ impl ProgramParser for TokenAccountParser {
     }
 
     fn handles_instructions(&self) -> bool {
-        false
+        true
     }
     fn handle_account(
         &self,
@@ -67,6 +89,15 @@ impl ProgramParser for TokenAccountParser {
 
                 TokenProgramAccount::Mint(mint)
             }
+            Multisig::LEN => {
+                let multisig = Multisig::unpack(account_data).map_err(|_| {
+                    BlockbusterError::CustomDeserializationError(
+                        "Token Multisig Unpack Failed".to_string(),
+                    )
+                })?;
+
+                TokenProgramAccount::Multisig(multisig)
+            }
             _ => {
                 return Err(BlockbusterError::InvalidDataLength);
             }
@@ -74,4 +105,19 @@ impl ProgramParser for TokenAccountParser {
 
         Ok(Box::new(account_type))
     }
+
+    fn handle_instruction(
+        &self,
+        bundle: &InstructionBundle,
+    ) -> Result<Box<(dyn ParseResult + 'static)>, BlockbusterError> {
+        let ix_data = match bundle.instruction {
+            Some(cix) => cix.data.as_ref(),
+            _ => return Err(BlockbusterError::DeserializationError),
+        };
+
+        Ok(Box::new(instruction::parse_instruction(
+            bundle.keys,
+            ix_data,
+        )?))
+    }
 }
@@ -1,4 +1,5 @@
-use bytemuck::Zeroable;
+use crate::error::BlockbusterError;
+use bytemuck::{Pod, Zeroable};
 use serde::{Deserialize, Serialize};
 use solana_zk_token_sdk::zk_token_elgamal::pod::{AeCiphertext, ElGamalCiphertext, ElGamalPubkey};
 use spl_pod::{
@@ -19,7 +20,7 @@ use spl_token_2022::extension::{
     mint_close_authority::MintCloseAuthority,
     permanent_delegate::PermanentDelegate,
     transfer_fee::{TransferFee, TransferFeeAmount, TransferFeeConfig},
-    transfer_hook::TransferHook,
+    transfer_hook::{TransferHook, TransferHookAccount},
 };
 
 use spl_token_group_interface::state::{TokenGroup, TokenGroupMember};
@@ -159,10 +160,77 @@ pub struct ShadowConfidentialTransferFeeConfig {
     pub withheld_amount: String,
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ShadowConfidentialTransferFeeAmount {
     pub withheld_amount: String,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Zeroable, Serialize, Deserialize)]
+pub struct ShadowTransferHookAccount {
+    /// Flag to indicate that the account is in the middle of a transfer
+    pub transferring: PodBool,
+}
+
+/// Mirrors `ScaledUiAmountConfig` from newer spl-token-2022 releases.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShadowScaledUiAmount {
+    pub authority: OptionalNonZeroPubkey,
+    pub multiplier: f64,
+    pub new_multiplier_effective_timestamp: UnixTimestamp,
+    pub new_multiplier: f64,
+}
+
+/// Mirrors `PausableConfig` from newer spl-token-2022 releases.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Zeroable, Serialize, Deserialize)]
+pub struct ShadowPausable {
+    pub authority: OptionalNonZeroPubkey,
+    pub paused: PodBool,
+}
+
+/// Mirrors `ConfidentialMintBurn` from newer spl-token-2022 releases.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShadowConfidentialMintBurn {
+    pub confidential_supply: String,
+    pub decryptable_supply: String,
+    pub supply_elgamal_pubkey: String,
+    pub pending_burn: String,
+}
+
+/// A TLV entry whose extension type this crate does not decode, kept so that new extensions can
+/// be detected.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct UnknownExtension {
+    pub extension_type: u16,
+    pub data: Vec<u8>,
+}
+
+// On-chain layouts of extensions that the pinned spl-token-2022 does not define.
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub(crate) struct ScaledUiAmountConfig {
+    authority: OptionalNonZeroPubkey,
+    multiplier: [u8; 8],
+    new_multiplier_effective_timestamp: UnixTimestamp,
+    new_multiplier: [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub(crate) struct PausableConfig {
+    authority: OptionalNonZeroPubkey,
+    paused: PodBool,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub(crate) struct ConfidentialMintBurn {
+    confidential_supply: ElGamalCiphertext,
+    decryptable_supply: AeCiphertext,
+    supply_elgamal_pubkey: ElGamalPubkey,
+    pending_burn: ElGamalCiphertext,
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Zeroable, Serialize, Deserialize)]
 pub struct ShadowTransferFeeAmount {
     pub withheld_amount: PodU64,
@@ -184,7 +252,95 @@ pub struct ShadowMetadata {
     pub name: String,
     pub symbol: String,
     pub uri: String,
-    pub additional_metadata: Vec<(String, String)>,
+    pub additional_metadata: AdditionalMetadata,
+}
+
+/// Token-2022 stores each extension behind a `u16` length, which bounds the serialized size of
+/// the whole `TokenMetadata` extension and therefore of its additional metadata.
+pub const MAX_ADDITIONAL_METADATA_LEN: usize = u16::MAX as usize;
+
+/// The `additional_metadata` key/value pairs of a `TokenMetadata` extension, in on-chain order.
+///
+/// Duplicate keys are kept as stored.  Lookups return the first entry for a key, which is the
+/// entry the token metadata interface updates.  Serializes as an array of `[key, value]` pairs.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<(String, String)>", into = "Vec<(String, String)>")]
+pub struct AdditionalMetadata(Vec<(String, String)>);
+
+impl AdditionalMetadata {
+    /// Wraps `entries`, failing if they could not fit in a `TokenMetadata` extension.
+    pub fn new(entries: Vec<(String, String)>) -> Result<Self, BlockbusterError> {
+        let additional_metadata = AdditionalMetadata(entries);
+        let len = additional_metadata.serialized_len();
+        if len > MAX_ADDITIONAL_METADATA_LEN {
+            return Err(BlockbusterError::AdditionalMetadataTooLong(
+                len,
+                MAX_ADDITIONAL_METADATA_LEN,
+            ));
+        }
+        Ok(additional_metadata)
+    }
+
+    /// Borsh-serialized length: a `u32` count, then each key and value with a `u32` length.
+    pub fn serialized_len(&self) -> usize {
+        self.0
+            .iter()
+            .map(|(key, value)| 8 + key.len() + value.len())
+            .sum::<usize>()
+            + 4
+    }
+
+    /// The value of the first entry for `key`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The values of every entry for `key`, in order.
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.0
+            .iter()
+            .filter(move |(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[(String, String)] {
+        &self.0
+    }
+}
+
+impl TryFrom<Vec<(String, String)>> for AdditionalMetadata {
+    type Error = BlockbusterError;
+
+    fn try_from(entries: Vec<(String, String)>) -> Result<Self, Self::Error> {
+        Self::new(entries)
+    }
+}
+
+impl From<AdditionalMetadata> for Vec<(String, String)> {
+    fn from(additional_metadata: AdditionalMetadata) -> Self {
+        additional_metadata.0
+    }
 }
 
 impl From<CpiGuard> for ShadowCpiGuard {
@@ -211,6 +367,45 @@ impl From<ConfidentialTransferFeeAmount> for ShadowConfidentialTransferFeeAmount
     }
 }
 
+impl From<TransferHookAccount> for ShadowTransferHookAccount {
+    fn from(original: TransferHookAccount) -> Self {
+        ShadowTransferHookAccount {
+            transferring: original.transferring,
+        }
+    }
+}
+
+impl From<ScaledUiAmountConfig> for ShadowScaledUiAmount {
+    fn from(original: ScaledUiAmountConfig) -> Self {
+        ShadowScaledUiAmount {
+            authority: original.authority,
+            multiplier: f64::from_le_bytes(original.multiplier),
+            new_multiplier_effective_timestamp: original.new_multiplier_effective_timestamp,
+            new_multiplier: f64::from_le_bytes(original.new_multiplier),
+        }
+    }
+}
+
+impl From<PausableConfig> for ShadowPausable {
+    fn from(original: PausableConfig) -> Self {
+        ShadowPausable {
+            authority: original.authority,
+            paused: original.paused,
+        }
+    }
+}
+
+impl From<ConfidentialMintBurn> for ShadowConfidentialMintBurn {
+    fn from(original: ConfidentialMintBurn) -> Self {
+        ShadowConfidentialMintBurn {
+            confidential_supply: original.confidential_supply.to_base58(),
+            decryptable_supply: original.decryptable_supply.to_base58(),
+            supply_elgamal_pubkey: original.supply_elgamal_pubkey.to_base58(),
+            pending_burn: original.pending_burn.to_base58(),
+        }
+    }
+}
+
 impl From<TransferFeeAmount> for ShadowTransferFeeAmount {
     fn from(original: TransferFeeAmount) -> Self {
         ShadowTransferFeeAmount {
@@ -294,6 +489,60 @@ impl From<TransferFeeConfig> for ShadowTransferFeeConfig {
     }
 }
 
+impl From<ShadowTransferFee> for TransferFee {
+    fn from(shadow: ShadowTransferFee) -> Self {
+        TransferFee {
+            epoch: shadow.epoch,
+            maximum_fee: shadow.maximum_fee,
+            transfer_fee_basis_points: shadow.transfer_fee_basis_points,
+        }
+    }
+}
+
+impl ShadowTransferFee {
+    /// Fee charged on a transfer of `pre_fee_amount`, rounded up and capped at `maximum_fee`.
+    pub fn calculate_fee(&self, pre_fee_amount: u64) -> Option<u64> {
+        TransferFee::from(*self).calculate_fee(pre_fee_amount)
+    }
+
+    /// Amount received by the destination of a transfer of `pre_fee_amount`.
+    pub fn calculate_post_fee_amount(&self, pre_fee_amount: u64) -> Option<u64> {
+        TransferFee::from(*self).calculate_post_fee_amount(pre_fee_amount)
+    }
+
+    /// Amount that must be sent for the destination to receive `post_fee_amount`.
+    pub fn calculate_pre_fee_amount(&self, post_fee_amount: u64) -> Option<u64> {
+        TransferFee::from(*self).calculate_pre_fee_amount(post_fee_amount)
+    }
+
+    /// Fee charged on a transfer for which the destination receives `post_fee_amount`.
+    pub fn calculate_inverse_fee(&self, post_fee_amount: u64) -> Option<u64> {
+        TransferFee::from(*self).calculate_inverse_fee(post_fee_amount)
+    }
+}
+
+impl ShadowTransferFeeConfig {
+    /// The transfer fee in effect at `epoch`.
+    pub fn get_epoch_fee(&self, epoch: u64) -> &ShadowTransferFee {
+        if epoch >= u64::from(self.newer_transfer_fee.epoch) {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+
+    /// Fee charged at `epoch` on a transfer of `pre_fee_amount`.
+    pub fn calculate_epoch_fee(&self, epoch: u64, pre_fee_amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch).calculate_fee(pre_fee_amount)
+    }
+
+    /// Fee charged at `epoch` on a transfer for which the destination receives `post_fee_amount`.
+    pub fn calculate_inverse_epoch_fee(&self, epoch: u64, post_fee_amount: u64) -> Option<u64> {
+        self.get_epoch_fee(epoch)
+            .calculate_inverse_fee(post_fee_amount)
+    }
+}
+
 impl From<InterestBearingConfig> for ShadowInterestBearingConfig {
     fn from(original: InterestBearingConfig) -> Self {
         ShadowInterestBearingConfig {
@@ -306,6 +555,86 @@ impl From<InterestBearingConfig> for ShadowInterestBearingConfig {
     }
 }
 
+impl From<ShadowInterestBearingConfig> for InterestBearingConfig {
+    fn from(shadow: ShadowInterestBearingConfig) -> Self {
+        InterestBearingConfig {
+            rate_authority: shadow.rate_authority,
+            initialization_timestamp: shadow.initialization_timestamp,
+            pre_update_average_rate: shadow.pre_update_average_rate,
+            last_update_timestamp: shadow.last_update_timestamp,
+            current_rate: shadow.current_rate,
+        }
+    }
+}
+
+impl ShadowInterestBearingConfig {
+    /// Converts a raw amount to its UI amount with interest accrued up to `unix_timestamp`.
+    pub fn amount_to_ui_amount(
+        &self,
+        amount: u64,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Option<String> {
+        InterestBearingConfig::from(*self).amount_to_ui_amount(amount, decimals, unix_timestamp)
+    }
+
+    /// Converts a UI amount with interest accrued up to `unix_timestamp` back to a raw amount.
+    pub fn try_ui_amount_into_amount(
+        &self,
+        ui_amount: &str,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Result<u64, BlockbusterError> {
+        InterestBearingConfig::from(*self)
+            .try_ui_amount_into_amount(ui_amount, decimals, unix_timestamp)
+            .map_err(|_| BlockbusterError::InvalidUiAmount)
+    }
+}
+
+impl ShadowScaledUiAmount {
+    /// The multiplier in effect at `unix_timestamp`.
+    pub fn current_multiplier(&self, unix_timestamp: i64) -> f64 {
+        if unix_timestamp >= i64::from(self.new_multiplier_effective_timestamp) {
+            self.new_multiplier
+        } else {
+            self.multiplier
+        }
+    }
+
+    /// Converts a raw amount to its UI amount at `unix_timestamp`.  The scaled amount is truncated
+    /// to a whole number of base units before decimals are applied, as Token-2022 does.
+    pub fn amount_to_ui_amount(
+        &self,
+        amount: u64,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Option<String> {
+        let scaled_amount = (amount as f64) * self.current_multiplier(unix_timestamp);
+        let ui_amount = scaled_amount.trunc() / 10_f64.powi(decimals as i32);
+        ui_amount.is_finite().then(|| ui_amount.to_string())
+    }
+
+    /// Converts a UI amount at `unix_timestamp` back to a raw amount, rounding to the nearest
+    /// base unit.
+    pub fn try_ui_amount_into_amount(
+        &self,
+        ui_amount: &str,
+        decimals: u8,
+        unix_timestamp: i64,
+    ) -> Result<u64, BlockbusterError> {
+        let ui_amount = ui_amount
+            .parse::<f64>()
+            .map_err(|_| BlockbusterError::InvalidUiAmount)?;
+        let amount =
+            ui_amount * 10_f64.powi(decimals as i32) / self.current_multiplier(unix_timestamp);
+        if amount > (u64::MAX as f64) || amount < 0.0 || amount.is_nan() {
+            Err(BlockbusterError::InvalidUiAmount)
+        } else {
+            Ok(amount.round() as u64)
+        }
+    }
+}
+
 impl From<MintCloseAuthority> for ShadowMintCloseAuthority {
     fn from(original: MintCloseAuthority) -> Self {
         ShadowMintCloseAuthority {
@@ -382,7 +711,8 @@ impl From<TokenMetadata> for ShadowMetadata {
             name: original.name,
             symbol: original.symbol,
             uri: original.uri,
-            additional_metadata: original.additional_metadata,
+            // Decoded from an on-chain extension, so already within the length limit.
+            additional_metadata: AdditionalMetadata(original.additional_metadata),
         }
     }
 }
@@ -0,0 +1,742 @@
+use crate::{
+    error::BlockbusterError,
+    instruction::get_key,
+    program_handler::ParseResult,
+    programs::{
+        token_account::instruction::{
+            decoded_token_instruction, get_signers, TokenInstructionAccounts,
+        },
+        ProgramParseResult,
+    },
+};
+use solana_sdk::pubkey::Pubkey;
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_2022::{
+    extension::{
+        confidential_transfer::instruction::ConfidentialTransferInstruction,
+        confidential_transfer_fee::instruction::ConfidentialTransferFeeInstruction,
+        cpi_guard::instruction::CpiGuardInstruction,
+        default_account_state::instruction::DefaultAccountStateInstruction,
+        group_member_pointer::instruction::{
+            self as group_member_pointer, GroupMemberPointerInstruction,
+        },
+        group_pointer::instruction::{self as group_pointer, GroupPointerInstruction},
+        interest_bearing_mint::{
+            instruction::{self as interest_bearing_mint, InterestBearingMintInstruction},
+            BasisPoints,
+        },
+        memo_transfer::instruction::RequiredMemoTransfersInstruction,
+        metadata_pointer::instruction::{self as metadata_pointer, MetadataPointerInstruction},
+        transfer_fee::instruction::TransferFeeInstruction,
+        transfer_hook::instruction::{self as transfer_hook, TransferHookInstruction},
+    },
+    instruction::{decode_instruction_data, decode_instruction_type, TokenInstruction},
+};
+use spl_token_group_interface::instruction::TokenGroupInstruction;
+use spl_token_metadata_interface::instruction::TokenMetadataInstruction;
+
+use super::extension::{
+    ShadowDefaultAccountState, ShadowGroupMemberPointer, ShadowGroupPointer, ShadowMetadataPointer,
+};
+
+/// A base Token-2022 instruction, or an extension instruction without sub-instructions such as
+/// `InitializeMintCloseAuthority` or `Reallocate`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TokenExtensionsBaseInstruction {
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "instruction",
+            serialize_with = "crate::serialize::token_instruction"
+        )
+    )]
+    instruction: DecodedTokenInstruction,
+    pub accounts: TokenInstructionAccounts,
+}
+
+impl TokenExtensionsBaseInstruction {
+    pub fn instruction(&self) -> TokenInstruction<'_> {
+        self.instruction.instruction()
+    }
+}
+
+decoded_token_instruction!(DecodedTokenInstruction:
+    InitializeMint { decimals, mint_authority, freeze_authority },
+    InitializeAccount,
+    InitializeMultisig { m },
+    Transfer { amount },
+    Approve { amount },
+    Revoke,
+    SetAuthority { authority_type, new_authority },
+    MintTo { amount },
+    Burn { amount },
+    CloseAccount,
+    FreezeAccount,
+    ThawAccount,
+    TransferChecked { amount, decimals },
+    ApproveChecked { amount, decimals },
+    MintToChecked { amount, decimals },
+    BurnChecked { amount, decimals },
+    InitializeAccount2 { owner },
+    SyncNative,
+    InitializeAccount3 { owner },
+    InitializeMultisig2 { m },
+    InitializeMint2 { decimals, mint_authority, freeze_authority },
+    GetAccountDataSize { extension_types },
+    InitializeImmutableOwner,
+    AmountToUiAmount { amount },
+    InitializeMintCloseAuthority { close_authority },
+    TransferFeeExtension(instruction),
+    ConfidentialTransferExtension,
+    DefaultAccountStateExtension,
+    Reallocate { extension_types },
+    MemoTransferExtension,
+    CreateNativeMint,
+    InitializeNonTransferableMint,
+    InterestBearingMintExtension,
+    CpiGuardExtension,
+    InitializePermanentDelegate { delegate },
+    TransferHookExtension,
+    ConfidentialTransferFeeExtension,
+    WithdrawExcessLamports,
+    MetadataPointerExtension,
+    GroupPointerExtension,
+    GroupMemberPointerExtension,
+);
+
+/// Accounts of a token-metadata-interface instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TokenMetadataInstructionAccounts {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+    pub metadata: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub update_authority: Option<Pubkey>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub mint: Option<Pubkey>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub mint_authority: Option<Pubkey>,
+}
+
+/// Accounts of a token-group-interface instruction.  For `InitializeMember`, `mint` and
+/// `mint_authority` are those of the member.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TokenGroupInstructionAccounts {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::serialize::pubkey"))]
+    pub group: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub update_authority: Option<Pubkey>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub mint: Option<Pubkey>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub mint_authority: Option<Pubkey>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::option_pubkey")
+    )]
+    pub member: Option<Pubkey>,
+}
+
+/// Decoded Token-2022 instructions, including extension sub-instructions and the token-metadata
+/// and token-group interface instructions that Token-2022 implements.
+///
+/// `authority` fields are only set by `Initialize` sub-instructions, since `Update`
+/// sub-instructions cannot change the authority.
+#[allow(clippy::large_enum_variant)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TokenExtensionsInstruction {
+    Token(TokenExtensionsBaseInstruction),
+    TransferFee {
+        instruction: TransferFeeInstruction,
+        accounts: TokenInstructionAccounts,
+    },
+    /// Confidential transfer instructions carry zero-knowledge proof data and a variable set of
+    /// accounts, so only the sub-instruction and the raw account list are decoded.
+    ConfidentialTransfer {
+        instruction: ConfidentialTransferInstruction,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::vec_pubkey")
+        )]
+        accounts: Vec<Pubkey>,
+    },
+    ConfidentialTransferFee {
+        instruction: ConfidentialTransferFeeInstruction,
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::vec_pubkey")
+        )]
+        accounts: Vec<Pubkey>,
+    },
+    DefaultAccountState {
+        instruction: DefaultAccountStateInstruction,
+        state: ShadowDefaultAccountState,
+        accounts: TokenInstructionAccounts,
+    },
+    MemoTransfer {
+        instruction: RequiredMemoTransfersInstruction,
+        accounts: TokenInstructionAccounts,
+    },
+    CpiGuard {
+        instruction: CpiGuardInstruction,
+        accounts: TokenInstructionAccounts,
+    },
+    InterestBearingMint {
+        instruction: InterestBearingMintInstruction,
+        rate_authority: Option<OptionalNonZeroPubkey>,
+        rate: BasisPoints,
+        accounts: TokenInstructionAccounts,
+    },
+    MetadataPointer {
+        instruction: MetadataPointerInstruction,
+        authority: Option<OptionalNonZeroPubkey>,
+        pointer: ShadowMetadataPointer,
+        accounts: TokenInstructionAccounts,
+    },
+    GroupPointer {
+        instruction: GroupPointerInstruction,
+        authority: Option<OptionalNonZeroPubkey>,
+        pointer: ShadowGroupPointer,
+        accounts: TokenInstructionAccounts,
+    },
+    GroupMemberPointer {
+        instruction: GroupMemberPointerInstruction,
+        authority: Option<OptionalNonZeroPubkey>,
+        pointer: ShadowGroupMemberPointer,
+        accounts: TokenInstructionAccounts,
+    },
+    TransferHook {
+        instruction: TransferHookInstruction,
+        authority: Option<OptionalNonZeroPubkey>,
+        program_id: OptionalNonZeroPubkey,
+        accounts: TokenInstructionAccounts,
+    },
+    TokenMetadata {
+        instruction: TokenMetadataInstruction,
+        accounts: TokenMetadataInstructionAccounts,
+    },
+    TokenGroup {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::token_group_instruction")
+        )]
+        instruction: TokenGroupInstruction,
+        accounts: TokenGroupInstructionAccounts,
+    },
+}
+
+impl ParseResult for TokenExtensionsInstruction {
+    fn result(&self) -> &Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+    fn result_type(&self) -> ProgramParseResult {
+        ProgramParseResult::TokenExtensionsInstruction(self)
+    }
+}
+
+/// Decodes Token-2022 instruction data.  `keys` are the instruction's accounts, in order.
+/// Interface instructions are tried after token instructions, matching the Token-2022 processor.
+pub fn parse_instruction(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+) -> Result<TokenExtensionsInstruction, BlockbusterError> {
+    if let Ok(instruction) = TokenInstruction::unpack(ix_data) {
+        parse_token_instruction(instruction, keys, ix_data)
+    } else if let Ok(instruction) = TokenMetadataInstruction::unpack(ix_data) {
+        let accounts = token_metadata_accounts(&instruction, keys)?;
+        Ok(TokenExtensionsInstruction::TokenMetadata {
+            instruction,
+            accounts,
+        })
+    } else if let Ok(instruction) = TokenGroupInstruction::unpack(ix_data) {
+        let accounts = token_group_accounts(&instruction, keys)?;
+        Ok(TokenExtensionsInstruction::TokenGroup {
+            instruction,
+            accounts,
+        })
+    } else {
+        Err(BlockbusterError::InstructionParsingError)
+    }
+}
+
+fn decode_type<T: TryFrom<u8>>(ext_data: &[u8]) -> Result<T, BlockbusterError> {
+    decode_instruction_type(ext_data).map_err(|_| BlockbusterError::InstructionParsingError)
+}
+
+fn decode_data<T: bytemuck::Pod>(ext_data: &[u8]) -> Result<T, BlockbusterError> {
+    decode_instruction_data(ext_data)
+        .copied()
+        .map_err(|_| BlockbusterError::InstructionParsingError)
+}
+
+// Accounts of extension `Initialize` sub-instructions, which only take the mint.
+fn mint_accounts(keys: &[Pubkey]) -> Result<TokenInstructionAccounts, BlockbusterError> {
+    Ok(TokenInstructionAccounts {
+        mint: Some(get_key(keys, 0)?),
+        ..Default::default()
+    })
+}
+
+// Accounts of extension sub-instructions that update a mint: mint, authority, signers.
+fn mint_authority_accounts(keys: &[Pubkey]) -> Result<TokenInstructionAccounts, BlockbusterError> {
+    Ok(TokenInstructionAccounts {
+        mint: Some(get_key(keys, 0)?),
+        authority: Some(get_key(keys, 1)?),
+        signers: get_signers(keys, 2),
+        ..Default::default()
+    })
+}
+
+// Accounts of extension sub-instructions that update a token account: account, owner, signers.
+fn account_owner_accounts(keys: &[Pubkey]) -> Result<TokenInstructionAccounts, BlockbusterError> {
+    Ok(TokenInstructionAccounts {
+        account: Some(get_key(keys, 0)?),
+        authority: Some(get_key(keys, 1)?),
+        signers: get_signers(keys, 2),
+        ..Default::default()
+    })
+}
+
+fn parse_token_instruction(
+    instruction: TokenInstruction,
+    keys: &[Pubkey],
+    ix_data: &[u8],
+) -> Result<TokenExtensionsInstruction, BlockbusterError> {
+    // Extension instructions are followed by their own sub-instruction type and data.
+    let ext_data = ix_data.get(1..).unwrap_or_default();
+
+    let parsed = match instruction {
+        TokenInstruction::TransferFeeExtension(instruction) => {
+            TokenExtensionsInstruction::TransferFee {
+                accounts: transfer_fee_accounts(&instruction, keys)?,
+                instruction,
+            }
+        }
+        TokenInstruction::ConfidentialTransferExtension => {
+            TokenExtensionsInstruction::ConfidentialTransfer {
+                instruction: decode_type(ext_data)?,
+                accounts: keys.to_vec(),
+            }
+        }
+        TokenInstruction::ConfidentialTransferFeeExtension => {
+            TokenExtensionsInstruction::ConfidentialTransferFee {
+                instruction: decode_type(ext_data)?,
+                accounts: keys.to_vec(),
+            }
+        }
+        TokenInstruction::DefaultAccountStateExtension => {
+            let instruction = decode_type(ext_data)?;
+            let accounts = match instruction {
+                DefaultAccountStateInstruction::Initialize => mint_accounts(keys)?,
+                DefaultAccountStateInstruction::Update => mint_authority_accounts(keys)?,
+            };
+            TokenExtensionsInstruction::DefaultAccountState {
+                instruction,
+                state: ShadowDefaultAccountState {
+                    state: decode_data(ext_data)?,
+                },
+                accounts,
+            }
+        }
+        TokenInstruction::MemoTransferExtension => TokenExtensionsInstruction::MemoTransfer {
+            instruction: decode_type(ext_data)?,
+            accounts: account_owner_accounts(keys)?,
+        },
+        TokenInstruction::CpiGuardExtension => TokenExtensionsInstruction::CpiGuard {
+            instruction: decode_type(ext_data)?,
+            accounts: account_owner_accounts(keys)?,
+        },
+        TokenInstruction::InterestBearingMintExtension => match decode_type(ext_data)? {
+            InterestBearingMintInstruction::Initialize => {
+                let data: interest_bearing_mint::InitializeInstructionData = decode_data(ext_data)?;
+                TokenExtensionsInstruction::InterestBearingMint {
+                    instruction: InterestBearingMintInstruction::Initialize,
+                    rate_authority: Some(data.rate_authority),
+                    rate: data.rate,
+                    accounts: mint_accounts(keys)?,
+                }
+            }
+            InterestBearingMintInstruction::UpdateRate => {
+                TokenExtensionsInstruction::InterestBearingMint {
+                    instruction: InterestBearingMintInstruction::UpdateRate,
+                    rate_authority: None,
+                    rate: decode_data(ext_data)?,
+                    accounts: mint_authority_accounts(keys)?,
+                }
+            }
+        },
+        TokenInstruction::MetadataPointerExtension => match decode_type(ext_data)? {
+            MetadataPointerInstruction::Initialize => {
+                let data: metadata_pointer::InitializeInstructionData = decode_data(ext_data)?;
+                TokenExtensionsInstruction::MetadataPointer {
+                    instruction: MetadataPointerInstruction::Initialize,
+                    authority: Some(data.authority),
+                    pointer: ShadowMetadataPointer {
+                        metadata_address: data.metadata_address,
+                    },
+                    accounts: mint_accounts(keys)?,
+                }
+            }
+            MetadataPointerInstruction::Update => {
+                let data: metadata_pointer::UpdateInstructionData = decode_data(ext_data)?;
+                TokenExtensionsInstruction::MetadataPointer {
+                    instruction: MetadataPointerInstruction::Update,
+                    authority: None,
+                    pointer: ShadowMetadataPointer {
+                        metadata_address: data.metadata_address,
+                    },
+                    accounts: mint_authority_accounts(keys)?,
+                }
+            }
+        },
+        TokenInstruction::GroupPointerExtension => match decode_type(ext_data)? {
+            GroupPointerInstruction::Initialize => {
+                let data: group_pointer::InitializeInstructionData = decode_data(ext_data)?;
+                TokenExtensionsInstruction::GroupPointer {
+                    instruction: GroupPointerInstruction::Initialize,
+                    authority: Some(data.authority),
+                    pointer: ShadowGroupPointer {
+                        group_address: data.group_address,
+                    },
+                    accounts: mint_accounts(keys)?,
+                }
+            }
+            GroupPointerInstruction::Update => {
+                let data: group_pointer::UpdateInstructionData = decode_data(ext_data)?;
+                TokenExtensionsInstruction::GroupPointer {
+                    instruction: GroupPointerInstruction::Update,
+                    authority: None,
+                    pointer: ShadowGroupPointer {
+                        group_address: data.group_address,
+                    },
+                    accounts: mint_authority_accounts(keys)?,
+                }
+            }
+        },
+        TokenInstruction::GroupMemberPointerExtension => match decode_type(ext_data)? {
+            GroupMemberPointerInstruction::Initialize => {
+                let data: group_member_pointer::InitializeInstructionData = decode_data(ext_data)?;
+                TokenExtensionsInstruction::GroupMemberPointer {
+                    instruction: GroupMemberPointerInstruction::Initialize,
+                    authority: Some(data.authority),
+                    pointer: ShadowGroupMemberPointer {
+                        member_address: data.member_address,
+                    },
+                    accounts: mint_accounts(keys)?,
+                }
+            }
+            GroupMemberPointerInstruction::Update => {
+                let data: group_member_pointer::UpdateInstructionData = decode_data(ext_data)?;
+                TokenExtensionsInstruction::GroupMemberPointer {
+                    instruction: GroupMemberPointerInstruction::Update,
+                    authority: None,
+                    pointer: ShadowGroupMemberPointer {
+                        member_address: data.member_address,
+                    },
+                    accounts: mint_authority_accounts(keys)?,
+                }
+            }
+        },
+        TokenInstruction::TransferHookExtension => match decode_type(ext_data)? {
+            TransferHookInstruction::Initialize => {
+                let data: transfer_hook::InitializeInstructionData = decode_data(ext_data)?;
+                TokenExtensionsInstruction::TransferHook {
+                    instruction: TransferHookInstruction::Initialize,
+                    authority: Some(data.authority),
+                    program_id: data.program_id,
+                    accounts: mint_accounts(keys)?,
+                }
+            }
+            TransferHookInstruction::Update => {
+                let data: transfer_hook::UpdateInstructionData = decode_data(ext_data)?;
+                TokenExtensionsInstruction::TransferHook {
+                    instruction: TransferHookInstruction::Update,
+                    authority: None,
+                    program_id: data.program_id,
+                    accounts: mint_authority_accounts(keys)?,
+                }
+            }
+        },
+        instruction => TokenExtensionsInstruction::Token(TokenExtensionsBaseInstruction {
+            accounts: base_accounts(&instruction, keys)?,
+            instruction: DecodedTokenInstruction::new(instruction),
+        }),
+    };
+
+    Ok(parsed)
+}
+
+// See Token-2022 for account positions:
+// https://github.com/solana-labs/solana-program-library/blob/master/token/program-2022/src/instruction.rs
+fn base_accounts(
+    instruction: &TokenInstruction,
+    keys: &[Pubkey],
+) -> Result<TokenInstructionAccounts, BlockbusterError> {
+    let accounts = match instruction {
+        TokenInstruction::InitializeMint { .. }
+        | TokenInstruction::InitializeMint2 { .. }
+        | TokenInstruction::GetAccountDataSize { .. }
+        | TokenInstruction::AmountToUiAmount { .. }
+        | TokenInstruction::UiAmountToAmount { .. }
+        | TokenInstruction::InitializeMintCloseAuthority { .. }
+        | TokenInstruction::InitializeNonTransferableMint
+        | TokenInstruction::InitializePermanentDelegate { .. } => mint_accounts(keys)?,
+        TokenInstruction::InitializeAccount => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            mint: Some(get_key(keys, 1)?),
+            authority: Some(get_key(keys, 2)?),
+            ..Default::default()
+        },
+        TokenInstruction::InitializeAccount2 { owner }
+        | TokenInstruction::InitializeAccount3 { owner } => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            mint: Some(get_key(keys, 1)?),
+            authority: Some(*owner),
+            ..Default::default()
+        },
+        TokenInstruction::InitializeMultisig { .. } => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            signers: get_signers(keys, 2),
+            ..Default::default()
+        },
+        TokenInstruction::InitializeMultisig2 { .. } => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            signers: get_signers(keys, 1),
+            ..Default::default()
+        },
+        #[allow(deprecated)]
+        TokenInstruction::Transfer { .. } => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            destination: Some(get_key(keys, 1)?),
+            authority: Some(get_key(keys, 2)?),
+            signers: get_signers(keys, 3),
+            ..Default::default()
+        },
+        TokenInstruction::Approve { .. } => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            delegate: Some(get_key(keys, 1)?),
+            authority: Some(get_key(keys, 2)?),
+            signers: get_signers(keys, 3),
+            ..Default::default()
+        },
+        TokenInstruction::Revoke => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            authority: Some(get_key(keys, 1)?),
+            signers: get_signers(keys, 2),
+            ..Default::default()
+        },
+        TokenInstruction::SetAuthority { .. } => account_owner_accounts(keys)?,
+        TokenInstruction::MintTo { .. } | TokenInstruction::MintToChecked { .. } => {
+            TokenInstructionAccounts {
+                mint: Some(get_key(keys, 0)?),
+                destination: Some(get_key(keys, 1)?),
+                authority: Some(get_key(keys, 2)?),
+                signers: get_signers(keys, 3),
+                ..Default::default()
+            }
+        }
+        TokenInstruction::Burn { .. } | TokenInstruction::BurnChecked { .. } => {
+            TokenInstructionAccounts {
+                source: Some(get_key(keys, 0)?),
+                mint: Some(get_key(keys, 1)?),
+                authority: Some(get_key(keys, 2)?),
+                signers: get_signers(keys, 3),
+                ..Default::default()
+            }
+        }
+        TokenInstruction::CloseAccount => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            destination: Some(get_key(keys, 1)?),
+            authority: Some(get_key(keys, 2)?),
+            signers: get_signers(keys, 3),
+            ..Default::default()
+        },
+        TokenInstruction::FreezeAccount | TokenInstruction::ThawAccount => {
+            TokenInstructionAccounts {
+                account: Some(get_key(keys, 0)?),
+                mint: Some(get_key(keys, 1)?),
+                authority: Some(get_key(keys, 2)?),
+                signers: get_signers(keys, 3),
+                ..Default::default()
+            }
+        }
+        TokenInstruction::TransferChecked { .. } => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            mint: Some(get_key(keys, 1)?),
+            destination: Some(get_key(keys, 2)?),
+            authority: Some(get_key(keys, 3)?),
+            signers: get_signers(keys, 4),
+            ..Default::default()
+        },
+        TokenInstruction::ApproveChecked { .. } => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            mint: Some(get_key(keys, 1)?),
+            delegate: Some(get_key(keys, 2)?),
+            authority: Some(get_key(keys, 3)?),
+            signers: get_signers(keys, 4),
+            ..Default::default()
+        },
+        TokenInstruction::SyncNative | TokenInstruction::InitializeImmutableOwner => {
+            TokenInstructionAccounts {
+                account: Some(get_key(keys, 0)?),
+                ..Default::default()
+            }
+        }
+        TokenInstruction::Reallocate { .. } => TokenInstructionAccounts {
+            account: Some(get_key(keys, 0)?),
+            authority: Some(get_key(keys, 3)?),
+            signers: get_signers(keys, 4),
+            ..Default::default()
+        },
+        TokenInstruction::CreateNativeMint => TokenInstructionAccounts {
+            mint: Some(get_key(keys, 1)?),
+            ..Default::default()
+        },
+        TokenInstruction::WithdrawExcessLamports => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            destination: Some(get_key(keys, 1)?),
+            authority: Some(get_key(keys, 2)?),
+            signers: get_signers(keys, 3),
+            ..Default::default()
+        },
+        // Extension instructions are decoded in `parse_token_instruction`.
+        _ => return Err(BlockbusterError::InstructionParsingError),
+    };
+
+    Ok(accounts)
+}
+
+fn transfer_fee_accounts(
+    instruction: &TransferFeeInstruction,
+    keys: &[Pubkey],
+) -> Result<TokenInstructionAccounts, BlockbusterError> {
+    let accounts = match instruction {
+        TransferFeeInstruction::InitializeTransferFeeConfig { .. } => mint_accounts(keys)?,
+        TransferFeeInstruction::TransferCheckedWithFee { .. } => TokenInstructionAccounts {
+            source: Some(get_key(keys, 0)?),
+            mint: Some(get_key(keys, 1)?),
+            destination: Some(get_key(keys, 2)?),
+            authority: Some(get_key(keys, 3)?),
+            signers: get_signers(keys, 4),
+            ..Default::default()
+        },
+        TransferFeeInstruction::WithdrawWithheldTokensFromMint => TokenInstructionAccounts {
+            mint: Some(get_key(keys, 0)?),
+            destination: Some(get_key(keys, 1)?),
+            authority: Some(get_key(keys, 2)?),
+            signers: get_signers(keys, 3),
+            ..Default::default()
+        },
+        TransferFeeInstruction::WithdrawWithheldTokensFromAccounts {
+            num_token_accounts, ..
+        } => {
+            // Multisig signers sit between the authority and the source accounts.
+            let sources_start = keys
+                .len()
+                .checked_sub(*num_token_accounts as usize)
+                .filter(|start| *start >= 3)
+                .ok_or(BlockbusterError::InstructionParsingError)?;
+            TokenInstructionAccounts {
+                mint: Some(get_key(keys, 0)?),
+                destination: Some(get_key(keys, 1)?),
+                authority: Some(get_key(keys, 2)?),
+                signers: keys[3..sources_start].to_vec(),
+                token_accounts: keys[sources_start..].to_vec(),
+                ..Default::default()
+            }
+        }
+        TransferFeeInstruction::HarvestWithheldTokensToMint => TokenInstructionAccounts {
+            mint: Some(get_key(keys, 0)?),
+            token_accounts: keys.get(1..).unwrap_or_default().to_vec(),
+            ..Default::default()
+        },
+        TransferFeeInstruction::SetTransferFee { .. } => mint_authority_accounts(keys)?,
+    };
+
+    Ok(accounts)
+}
+
+fn token_metadata_accounts(
+    instruction: &TokenMetadataInstruction,
+    keys: &[Pubkey],
+) -> Result<TokenMetadataInstructionAccounts, BlockbusterError> {
+    let metadata = get_key(keys, 0)?;
+    let accounts = match instruction {
+        TokenMetadataInstruction::Initialize(_) => TokenMetadataInstructionAccounts {
+            metadata,
+            update_authority: Some(get_key(keys, 1)?),
+            mint: Some(get_key(keys, 2)?),
+            mint_authority: Some(get_key(keys, 3)?),
+        },
+        TokenMetadataInstruction::UpdateField(_)
+        | TokenMetadataInstruction::RemoveKey(_)
+        | TokenMetadataInstruction::UpdateAuthority(_) => TokenMetadataInstructionAccounts {
+            metadata,
+            update_authority: Some(get_key(keys, 1)?),
+            mint: None,
+            mint_authority: None,
+        },
+        TokenMetadataInstruction::Emit(_) => TokenMetadataInstructionAccounts {
+            metadata,
+            update_authority: None,
+            mint: None,
+            mint_authority: None,
+        },
+    };
+
+    Ok(accounts)
+}
+
+fn token_group_accounts(
+    instruction: &TokenGroupInstruction,
+    keys: &[Pubkey],
+) -> Result<TokenGroupInstructionAccounts, BlockbusterError> {
+    let accounts = match instruction {
+        TokenGroupInstruction::InitializeGroup(_) => TokenGroupInstructionAccounts {
+            group: get_key(keys, 0)?,
+            update_authority: None,
+            mint: Some(get_key(keys, 1)?),
+            mint_authority: Some(get_key(keys, 2)?),
+            member: None,
+        },
+        TokenGroupInstruction::UpdateGroupMaxSize(_)
+        | TokenGroupInstruction::UpdateGroupAuthority(_) => TokenGroupInstructionAccounts {
+            group: get_key(keys, 0)?,
+            update_authority: Some(get_key(keys, 1)?),
+            mint: None,
+            mint_authority: None,
+            member: None,
+        },
+        TokenGroupInstruction::InitializeMember(_) => TokenGroupInstructionAccounts {
+            group: get_key(keys, 3)?,
+            update_authority: Some(get_key(keys, 4)?),
+            mint: Some(get_key(keys, 1)?),
+            mint_authority: Some(get_key(keys, 2)?),
+            member: Some(get_key(keys, 0)?),
+        },
+    };
+
+    Ok(accounts)
+}
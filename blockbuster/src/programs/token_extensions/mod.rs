@@ -1,16 +1,21 @@
 pub mod extension;
+pub mod instruction;
+pub mod pointer;
 use crate::{
     error::BlockbusterError,
+    instruction::InstructionBundle,
     program_handler::{ParseResult, ProgramParser},
     programs::ProgramParseResult,
 };
 
+use borsh::BorshDeserialize;
+use bytemuck::Pod;
 use serde::{Deserialize, Serialize};
-use solana_sdk::{pubkey::Pubkey, pubkeys};
+use solana_sdk::{program_pack::Pack, pubkey::Pubkey, pubkeys};
 use spl_token_2022::{
     extension::{
         confidential_transfer::{ConfidentialTransferAccount, ConfidentialTransferMint},
-        confidential_transfer_fee::ConfidentialTransferFeeConfig,
+        confidential_transfer_fee::{ConfidentialTransferFeeAmount, ConfidentialTransferFeeConfig},
         cpi_guard::CpiGuard,
         default_account_state::DefaultAccountState,
         group_member_pointer::GroupMemberPointer,
@@ -23,23 +28,31 @@ use spl_token_2022::{
         non_transferable::{NonTransferable, NonTransferableAccount},
         permanent_delegate::PermanentDelegate,
         transfer_fee::{TransferFeeAmount, TransferFeeConfig},
-        transfer_hook::TransferHook,
-        BaseStateWithExtensions, StateWithExtensions,
+        transfer_hook::{TransferHook, TransferHookAccount},
+        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
     },
-    state::{Account, Mint},
+    state::{Account, Mint, Multisig},
 };
 use spl_token_group_interface::state::{TokenGroup, TokenGroupMember};
 use spl_token_metadata_interface::state::TokenMetadata;
 
 use self::extension::{
-    ShadowConfidentialTransferAccount, ShadowConfidentialTransferFeeConfig,
-    ShadowConfidentialTransferMint, ShadowCpiGuard, ShadowDefaultAccountState,
-    ShadowGroupMemberPointer, ShadowGroupPointer, ShadowInterestBearingConfig, ShadowMemoTransfer,
-    ShadowMetadata, ShadowMetadataPointer, ShadowMintCloseAuthority, ShadowPermanentDelegate,
+    ConfidentialMintBurn, PausableConfig, ScaledUiAmountConfig, ShadowConfidentialMintBurn,
+    ShadowConfidentialTransferAccount, ShadowConfidentialTransferFeeAmount,
+    ShadowConfidentialTransferFeeConfig, ShadowConfidentialTransferMint, ShadowCpiGuard,
+    ShadowDefaultAccountState, ShadowGroupMemberPointer, ShadowGroupPointer,
+    ShadowInterestBearingConfig, ShadowMemoTransfer, ShadowMetadata, ShadowMetadataPointer,
+    ShadowMintCloseAuthority, ShadowPausable, ShadowPermanentDelegate, ShadowScaledUiAmount,
     ShadowTokenGroup, ShadowTokenGroupMember, ShadowTransferFeeAmount, ShadowTransferFeeConfig,
-    ShadowTransferHook,
+    ShadowTransferHook, ShadowTransferHookAccount, UnknownExtension,
 };
 
+// Extension types added to Token-2022 after the spl-token-2022 release this crate depends on.
+const CONFIDENTIAL_MINT_BURN: u16 = 24;
+const SCALED_UI_AMOUNT: u16 = 25;
+const PAUSABLE: u16 = 26;
+const PAUSABLE_ACCOUNT: u16 = 27;
+
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct MintAccountExtensions {
     pub default_account_state: Option<ShadowDefaultAccountState>,
@@ -56,10 +69,13 @@ pub struct MintAccountExtensions {
     pub token_group: Option<ShadowTokenGroup>,
     pub group_member_pointer: Option<ShadowGroupMemberPointer>,
     pub token_group_member: Option<ShadowTokenGroupMember>,
-    // TODO : add this when spl-token-2022 is updated
-    // pub scaled_ui_amount: Option<ShadowScaledUiAmount>,
+    pub scaled_ui_amount: Option<ShadowScaledUiAmount>,
+    pub pausable: Option<ShadowPausable>,
+    pub confidential_mint_burn: Option<ShadowConfidentialMintBurn>,
     pub non_transferable: Option<bool>,
     pub immutable_owner: Option<bool>,
+    /// Extensions this crate does not decode, in TLV order.
+    pub unknown_extensions: Vec<UnknownExtension>,
 }
 
 impl MintAccountExtensions {
@@ -78,6 +94,12 @@ impl MintAccountExtensions {
             || self.token_group.is_some()
             || self.group_member_pointer.is_some()
             || self.token_group_member.is_some()
+            || self.scaled_ui_amount.is_some()
+            || self.pausable.is_some()
+            || self.confidential_mint_burn.is_some()
+            || self.non_transferable.is_some()
+            || self.immutable_owner.is_some()
+            || !self.unknown_extensions.is_empty()
     }
 }
 
@@ -87,8 +109,13 @@ pub struct TokenAccountExtensions {
     pub cpi_guard: Option<ShadowCpiGuard>,
     pub memo_transfer: Option<ShadowMemoTransfer>,
     pub transfer_fee_amount: Option<ShadowTransferFeeAmount>,
+    pub confidential_transfer_fee_amount: Option<ShadowConfidentialTransferFeeAmount>,
+    pub transfer_hook_account: Option<ShadowTransferHookAccount>,
     pub immutable_owner: Option<bool>,
     pub non_transferable_account: Option<bool>,
+    pub pausable_account: Option<bool>,
+    /// Extensions this crate does not decode, in TLV order.
+    pub unknown_extensions: Vec<UnknownExtension>,
 }
 
 impl TokenAccountExtensions {
@@ -97,20 +124,80 @@ impl TokenAccountExtensions {
             || self.cpi_guard.is_some()
             || self.memo_transfer.is_some()
             || self.transfer_fee_amount.is_some()
+            || self.confidential_transfer_fee_amount.is_some()
+            || self.transfer_hook_account.is_some()
+            || self.immutable_owner.is_some()
+            || self.non_transferable_account.is_some()
+            || self.pausable_account.is_some()
+            || !self.unknown_extensions.is_empty()
     }
 }
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TokenAccount {
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::token_2022_account")
+    )]
     pub account: Account,
     pub extensions: TokenAccountExtensions,
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct MintAccount {
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::serialize::token_2022_mint")
+    )]
     pub account: Mint,
     pub extensions: MintAccountExtensions,
 }
 
+impl TokenAccount {
+    /// The account balance as displayed by wallets at `unix_timestamp`.  `mint` must be this
+    /// account's mint.
+    pub fn ui_amount(&self, mint: &MintAccount, unix_timestamp: i64) -> Option<String> {
+        mint.amount_to_ui_amount(self.account.amount, unix_timestamp)
+    }
+}
+
+impl MintAccount {
+    /// Converts a raw amount to its UI amount at `unix_timestamp`, matching Token-2022's
+    /// `AmountToUiAmount`: interest-bearing mints accrue interest, scaled UI amount mints apply
+    /// their multiplier, and other mints shift by `decimals` with trailing zeros trimmed.
+    pub fn amount_to_ui_amount(&self, amount: u64, unix_timestamp: i64) -> Option<String> {
+        let decimals = self.account.decimals;
+        if let Some(config) = &self.extensions.interest_bearing_config {
+            config.amount_to_ui_amount(amount, decimals, unix_timestamp)
+        } else if let Some(config) = &self.extensions.scaled_ui_amount {
+            config.amount_to_ui_amount(amount, decimals, unix_timestamp)
+        } else {
+            Some(spl_token_2022::amount_to_ui_amount_string_trimmed(
+                amount, decimals,
+            ))
+        }
+    }
+
+    /// Converts a UI amount at `unix_timestamp` back to a raw amount, matching Token-2022's
+    /// `UiAmountToAmount`.
+    pub fn try_ui_amount_into_amount(
+        &self,
+        ui_amount: &str,
+        unix_timestamp: i64,
+    ) -> Result<u64, BlockbusterError> {
+        let decimals = self.account.decimals;
+        if let Some(config) = &self.extensions.interest_bearing_config {
+            config.try_ui_amount_into_amount(ui_amount, decimals, unix_timestamp)
+        } else if let Some(config) = &self.extensions.scaled_ui_amount {
+            config.try_ui_amount_into_amount(ui_amount, decimals, unix_timestamp)
+        } else {
+            spl_token_2022::try_ui_amount_into_amount(ui_amount.to_string(), decimals)
+                .map_err(|_| BlockbusterError::InvalidUiAmount)
+        }
+    }
+}
+
 pubkeys!(
     token_program_id,
     "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
@@ -119,9 +206,17 @@ pubkeys!(
 pub struct Token2022AccountParser;
 
 #[allow(clippy::large_enum_variant)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum TokenExtensionsProgramAccount {
     TokenAccount(TokenAccount),
     MintAccount(MintAccount),
+    Multisig(
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::token_2022_multisig")
+        )]
+        Multisig,
+    ),
     EmptyAccount,
 }
 
@@ -149,7 +244,22 @@ impl ProgramParser for Token2022AccountParser {
     }
 
     fn handles_instructions(&self) -> bool {
-        false
+        true
+    }
+
+    fn handle_instruction(
+        &self,
+        bundle: &InstructionBundle,
+    ) -> Result<Box<(dyn ParseResult + 'static)>, BlockbusterError> {
+        let ix_data = match bundle.instruction {
+            Some(cix) => cix.data.as_ref(),
+            _ => return Err(BlockbusterError::DeserializationError),
+        };
+
+        Ok(Box::new(instruction::parse_instruction(
+            bundle.keys,
+            ix_data,
+        )?))
     }
 
     fn handle_account(
@@ -163,100 +273,23 @@ impl ProgramParser for Token2022AccountParser {
         let result: TokenExtensionsProgramAccount;
 
         if let Ok(account) = StateWithExtensions::<Account>::unpack(account_data) {
-            let confidential_transfer = account
-                .get_extension::<ConfidentialTransferAccount>()
-                .ok()
-                .copied();
-            let cpi_guard = account.get_extension::<CpiGuard>().ok().copied();
-            let memo_transfer = account.get_extension::<MemoTransfer>().ok().copied();
-            let transfer_fee_amount = account.get_extension::<TransferFeeAmount>().ok().copied();
-            let immutable_owner = account
-                .get_extension::<ImmutableOwner>()
-                .ok()
-                .copied()
-                .map(|_| true);
-            let non_transferable_account = account
-                .get_extension::<NonTransferableAccount>()
-                .ok()
-                .copied()
-                .map(|_| true);
-
-            // Create a structured account with extensions
-            let structured_account = TokenAccount {
+            result = TokenExtensionsProgramAccount::TokenAccount(TokenAccount {
                 account: account.base,
-                extensions: TokenAccountExtensions {
-                    confidential_transfer: confidential_transfer
-                        .map(ShadowConfidentialTransferAccount::from),
-                    cpi_guard: cpi_guard.map(ShadowCpiGuard::from),
-                    memo_transfer: memo_transfer.map(ShadowMemoTransfer::from),
-                    transfer_fee_amount: transfer_fee_amount.map(ShadowTransferFeeAmount::from),
-                    immutable_owner,
-                    non_transferable_account,
-                },
-            };
-
-            result = TokenExtensionsProgramAccount::TokenAccount(structured_account);
+                extensions: token_account_extensions(account.get_tlv_data()),
+            });
         } else if let Ok(mint) = StateWithExtensions::<Mint>::unpack(account_data) {
-            let confidential_transfer_mint = mint
-                .get_extension::<ConfidentialTransferMint>()
-                .ok()
-                .copied();
-
-            let confidential_transfer_fee_config = mint
-                .get_extension::<ConfidentialTransferFeeConfig>()
-                .ok()
-                .copied();
-            let default_account_state = mint.get_extension::<DefaultAccountState>().ok().copied();
-            let interest_bearing_config =
-                mint.get_extension::<InterestBearingConfig>().ok().copied();
-            let transfer_fee_config = mint.get_extension::<TransferFeeConfig>().ok().copied();
-            let mint_close_authority = mint.get_extension::<MintCloseAuthority>().ok().copied();
-            let permanent_delegate = mint.get_extension::<PermanentDelegate>().ok().copied();
-            let metadata_pointer = mint.get_extension::<MetadataPointer>().ok().copied();
-            let metadata = mint.get_variable_len_extension::<TokenMetadata>().ok();
-            let group_pointer = mint.get_extension::<GroupPointer>().ok().copied();
-            let token_group = mint.get_extension::<TokenGroup>().ok().copied();
-            let group_member_pointer = mint.get_extension::<GroupMemberPointer>().ok().copied();
-            let token_group_member = mint.get_extension::<TokenGroupMember>().ok().copied();
-            let transfer_hook = mint.get_extension::<TransferHook>().ok().copied();
-            let non_transferable = mint
-                .get_extension::<NonTransferable>()
-                .ok()
-                .copied()
-                .map(|_| true);
-
-            let immutable_owner = mint
-                .get_extension::<ImmutableOwner>()
-                .ok()
-                .copied()
-                .map(|_| true);
-
-            let structured_mint = MintAccount {
+            result = TokenExtensionsProgramAccount::MintAccount(MintAccount {
                 account: mint.base,
-                extensions: MintAccountExtensions {
-                    confidential_transfer_mint: confidential_transfer_mint
-                        .map(ShadowConfidentialTransferMint::from),
-                    confidential_transfer_fee_config: confidential_transfer_fee_config
-                        .map(ShadowConfidentialTransferFeeConfig::from),
-                    default_account_state: default_account_state
-                        .map(ShadowDefaultAccountState::from),
-                    interest_bearing_config: interest_bearing_config
-                        .map(ShadowInterestBearingConfig::from),
-                    transfer_fee_config: transfer_fee_config.map(ShadowTransferFeeConfig::from),
-                    mint_close_authority: mint_close_authority.map(ShadowMintCloseAuthority::from),
-                    permanent_delegate: permanent_delegate.map(ShadowPermanentDelegate::from),
-                    metadata_pointer: metadata_pointer.map(ShadowMetadataPointer::from),
-                    metadata: metadata.map(ShadowMetadata::from),
-                    transfer_hook: transfer_hook.map(ShadowTransferHook::from),
-                    group_pointer: group_pointer.map(ShadowGroupPointer::from),
-                    token_group: token_group.map(ShadowTokenGroup::from),
-                    group_member_pointer: group_member_pointer.map(ShadowGroupMemberPointer::from),
-                    token_group_member: token_group_member.map(ShadowTokenGroupMember::from),
-                    non_transferable,
-                    immutable_owner,
-                },
-            };
-            result = TokenExtensionsProgramAccount::MintAccount(structured_mint);
+                extensions: mint_extensions(mint.get_tlv_data()),
+            });
+        } else if account_data.len() == Multisig::LEN {
+            let multisig = Multisig::unpack(account_data).map_err(|_| {
+                BlockbusterError::CustomDeserializationError(
+                    "Token Multisig Unpack Failed".to_string(),
+                )
+            })?;
+
+            result = TokenExtensionsProgramAccount::Multisig(multisig);
         } else {
             return Err(BlockbusterError::InvalidDataLength);
         };
@@ -264,3 +297,170 @@ impl ProgramParser for Token2022AccountParser {
         Ok(Box::new(result))
     }
 }
+
+/// Splits TLV extension data into `(extension_type, value)` entries.  The walk stops at the first
+/// uninitialized entry or at a truncated entry, like the Token-2022 program does.
+fn tlv_entries(tlv_data: &[u8]) -> Vec<(u16, &[u8])> {
+    let mut entries = Vec::new();
+    let mut rest = tlv_data;
+    while rest.len() >= 4 {
+        let extension_type = u16::from_le_bytes([rest[0], rest[1]]);
+        if extension_type == u16::from(ExtensionType::Uninitialized) {
+            break;
+        }
+        let length = u16::from_le_bytes([rest[2], rest[3]]) as usize;
+        let Some(value) = rest.get(4..4 + length) else {
+            break;
+        };
+        entries.push((extension_type, value));
+        rest = &rest[4 + length..];
+    }
+    entries
+}
+
+fn pod<T: Pod>(value: &[u8]) -> Option<T> {
+    bytemuck::try_from_bytes::<T>(value).ok().copied()
+}
+
+// Stores a decoded extension, returning whether decoding succeeded.
+fn set<T, S: From<T>>(field: &mut Option<S>, value: Option<T>) -> bool {
+    *field = value.map(S::from);
+    field.is_some()
+}
+
+fn token_account_extensions(tlv_data: &[u8]) -> TokenAccountExtensions {
+    let mut extensions = TokenAccountExtensions::default();
+    for (extension_type, value) in tlv_entries(tlv_data) {
+        let decoded = match ExtensionType::try_from(extension_type) {
+            Ok(ExtensionType::ConfidentialTransferAccount) => set(
+                &mut extensions.confidential_transfer,
+                pod::<ConfidentialTransferAccount>(value),
+            ),
+            Ok(ExtensionType::CpiGuard) => set(&mut extensions.cpi_guard, pod::<CpiGuard>(value)),
+            Ok(ExtensionType::MemoTransfer) => {
+                set(&mut extensions.memo_transfer, pod::<MemoTransfer>(value))
+            }
+            Ok(ExtensionType::TransferFeeAmount) => set(
+                &mut extensions.transfer_fee_amount,
+                pod::<TransferFeeAmount>(value),
+            ),
+            Ok(ExtensionType::ConfidentialTransferFeeAmount) => set(
+                &mut extensions.confidential_transfer_fee_amount,
+                pod::<ConfidentialTransferFeeAmount>(value),
+            ),
+            Ok(ExtensionType::TransferHookAccount) => set(
+                &mut extensions.transfer_hook_account,
+                pod::<TransferHookAccount>(value),
+            ),
+            Ok(ExtensionType::ImmutableOwner) => set(
+                &mut extensions.immutable_owner,
+                pod::<ImmutableOwner>(value).map(|_| true),
+            ),
+            Ok(ExtensionType::NonTransferableAccount) => set(
+                &mut extensions.non_transferable_account,
+                pod::<NonTransferableAccount>(value).map(|_| true),
+            ),
+            _ if extension_type == PAUSABLE_ACCOUNT => {
+                set(&mut extensions.pausable_account, Some(true))
+            }
+            _ => false,
+        };
+
+        if !decoded {
+            extensions.unknown_extensions.push(UnknownExtension {
+                extension_type,
+                data: value.to_vec(),
+            });
+        }
+    }
+    extensions
+}
+
+fn mint_extensions(tlv_data: &[u8]) -> MintAccountExtensions {
+    let mut extensions = MintAccountExtensions::default();
+    for (extension_type, value) in tlv_entries(tlv_data) {
+        let decoded = match ExtensionType::try_from(extension_type) {
+            Ok(ExtensionType::ConfidentialTransferMint) => set(
+                &mut extensions.confidential_transfer_mint,
+                pod::<ConfidentialTransferMint>(value),
+            ),
+            Ok(ExtensionType::ConfidentialTransferFeeConfig) => set(
+                &mut extensions.confidential_transfer_fee_config,
+                pod::<ConfidentialTransferFeeConfig>(value),
+            ),
+            Ok(ExtensionType::DefaultAccountState) => set(
+                &mut extensions.default_account_state,
+                pod::<DefaultAccountState>(value),
+            ),
+            Ok(ExtensionType::InterestBearingConfig) => set(
+                &mut extensions.interest_bearing_config,
+                pod::<InterestBearingConfig>(value),
+            ),
+            Ok(ExtensionType::TransferFeeConfig) => set(
+                &mut extensions.transfer_fee_config,
+                pod::<TransferFeeConfig>(value),
+            ),
+            Ok(ExtensionType::MintCloseAuthority) => set(
+                &mut extensions.mint_close_authority,
+                pod::<MintCloseAuthority>(value),
+            ),
+            Ok(ExtensionType::PermanentDelegate) => set(
+                &mut extensions.permanent_delegate,
+                pod::<PermanentDelegate>(value),
+            ),
+            Ok(ExtensionType::MetadataPointer) => set(
+                &mut extensions.metadata_pointer,
+                pod::<MetadataPointer>(value),
+            ),
+            Ok(ExtensionType::TokenMetadata) => set(
+                &mut extensions.metadata,
+                TokenMetadata::deserialize(&mut &value[..]).ok(),
+            ),
+            Ok(ExtensionType::TransferHook) => {
+                set(&mut extensions.transfer_hook, pod::<TransferHook>(value))
+            }
+            Ok(ExtensionType::GroupPointer) => {
+                set(&mut extensions.group_pointer, pod::<GroupPointer>(value))
+            }
+            Ok(ExtensionType::TokenGroup) => {
+                set(&mut extensions.token_group, pod::<TokenGroup>(value))
+            }
+            Ok(ExtensionType::GroupMemberPointer) => set(
+                &mut extensions.group_member_pointer,
+                pod::<GroupMemberPointer>(value),
+            ),
+            Ok(ExtensionType::TokenGroupMember) => set(
+                &mut extensions.token_group_member,
+                pod::<TokenGroupMember>(value),
+            ),
+            Ok(ExtensionType::NonTransferable) => set(
+                &mut extensions.non_transferable,
+                pod::<NonTransferable>(value).map(|_| true),
+            ),
+            Ok(ExtensionType::ImmutableOwner) => set(
+                &mut extensions.immutable_owner,
+                pod::<ImmutableOwner>(value).map(|_| true),
+            ),
+            _ if extension_type == SCALED_UI_AMOUNT => set(
+                &mut extensions.scaled_ui_amount,
+                pod::<ScaledUiAmountConfig>(value),
+            ),
+            _ if extension_type == PAUSABLE => {
+                set(&mut extensions.pausable, pod::<PausableConfig>(value))
+            }
+            _ if extension_type == CONFIDENTIAL_MINT_BURN => set(
+                &mut extensions.confidential_mint_burn,
+                pod::<ConfidentialMintBurn>(value),
+            ),
+            _ => false,
+        };
+
+        if !decoded {
+            extensions.unknown_extensions.push(UnknownExtension {
+                extension_type,
+                data: value.to_vec(),
+            });
+        }
+    }
+    extensions
+}
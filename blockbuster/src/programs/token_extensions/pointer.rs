@@ -0,0 +1,107 @@
+use crate::{
+    error::BlockbusterError,
+    parser_registry::ParserRegistry,
+    program_handler::{ParseResult, ProgramParser},
+    programs::token_metadata::TokenMetadataParser,
+};
+use mpl_token_metadata::accounts::Metadata;
+use solana_sdk::{account::Account, pubkey::Pubkey};
+
+use super::{
+    extension::{ShadowMetadata, ShadowTokenGroup},
+    MintAccount,
+};
+
+/// The account a Token-2022 metadata or group pointer refers to.
+pub enum ResolvedPointer<'a, T> {
+    /// The pointer refers to the mint itself, so the data is stored in one of its extensions.
+    Mint(&'a T),
+    /// The pointer refers to another account.  `parsed` is `None` when no registered parser
+    /// handles accounts owned by `owner`.
+    Account {
+        address: Pubkey,
+        owner: Pubkey,
+        parsed: Option<Box<dyn ParseResult>>,
+    },
+}
+
+impl MintAccount {
+    /// Follows the mint's metadata pointer.  `mint_address` is the address of this mint and
+    /// `lookup` fetches the account the pointer refers to when it is not the mint.
+    ///
+    /// Token Metadata PDAs are always decoded with `TokenMetadataParser`; other accounts are
+    /// decoded by the parser `registry` has for their owner.  Returns `Ok(None)` if the mint has
+    /// no pointer, or if the account it points to does not exist.
+    pub fn resolve_metadata<F>(
+        &self,
+        mint_address: &Pubkey,
+        registry: &ParserRegistry,
+        lookup: F,
+    ) -> Result<Option<ResolvedPointer<'_, ShadowMetadata>>, BlockbusterError>
+    where
+        F: FnOnce(&Pubkey) -> Option<Account>,
+    {
+        let address = self
+            .extensions
+            .metadata_pointer
+            .and_then(|pointer| Option::<Pubkey>::from(pointer.metadata_address));
+        let Some(address) = address else {
+            return Ok(None);
+        };
+
+        if address == *mint_address {
+            return Ok(self.extensions.metadata.as_ref().map(ResolvedPointer::Mint));
+        }
+        let Some(account) = lookup(&address) else {
+            return Ok(None);
+        };
+
+        let parsed = if address == Metadata::find_pda(mint_address).0 {
+            Some(TokenMetadataParser.handle_account(&account.data)?)
+        } else {
+            registry.parse_account(&account.owner, &account.data)?
+        };
+
+        Ok(Some(ResolvedPointer::Account {
+            address,
+            owner: account.owner,
+            parsed,
+        }))
+    }
+
+    /// Follows the mint's group pointer, like `resolve_metadata`.
+    pub fn resolve_group<F>(
+        &self,
+        mint_address: &Pubkey,
+        registry: &ParserRegistry,
+        lookup: F,
+    ) -> Result<Option<ResolvedPointer<'_, ShadowTokenGroup>>, BlockbusterError>
+    where
+        F: FnOnce(&Pubkey) -> Option<Account>,
+    {
+        let address = self
+            .extensions
+            .group_pointer
+            .and_then(|pointer| Option::<Pubkey>::from(pointer.group_address));
+        let Some(address) = address else {
+            return Ok(None);
+        };
+
+        if address == *mint_address {
+            return Ok(self
+                .extensions
+                .token_group
+                .as_ref()
+                .map(ResolvedPointer::Mint));
+        }
+        let Some(account) = lookup(&address) else {
+            return Ok(None);
+        };
+
+        Ok(Some(ResolvedPointer::Account {
+            address,
+            owner: account.owner,
+            parsed: registry.parse_account(&account.owner, &account.data)?,
+        }))
+    }
+}
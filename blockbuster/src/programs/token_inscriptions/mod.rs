@@ -99,6 +99,7 @@ impl InscriptionData {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TokenInscriptionAccount {
     pub data: InscriptionData,
 }
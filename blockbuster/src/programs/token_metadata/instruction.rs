@@ -0,0 +1,596 @@
+use crate::{
+    error::BlockbusterError,
+    instruction::{get_key, get_optional_key},
+    program_handler::ParseResult,
+    programs::ProgramParseResult,
+};
+use borsh::BorshDeserialize;
+use mpl_token_metadata::{
+    instructions::{
+        Burn, BurnNft, Create, CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs,
+        Delegate, Lock, Mint, Print, Revoke, SetAndVerifyCollection,
+        SetAndVerifySizedCollectionItem, SignMetadata, Transfer, Unlock, Unverify,
+        UnverifyCollection, UnverifySizedCollectionItem, Update, UpdateMetadataAccountV2,
+        UpdateMetadataAccountV2InstructionArgs, Verify, VerifyCollection,
+        VerifySizedCollectionItem,
+    },
+    types::{
+        BurnArgs, CreateArgs, DelegateArgs, LockArgs, MintArgs, PrintArgs, RevokeArgs,
+        TransferArgs, UnlockArgs, UpdateArgs, VerificationArgs,
+    },
+    ID,
+};
+use solana_sdk::pubkey::Pubkey;
+
+const SIGN_METADATA_DISCRIMINATOR: u8 = 7;
+const UPDATE_METADATA_ACCOUNT_V2_DISCRIMINATOR: u8 = 15;
+const VERIFY_COLLECTION_DISCRIMINATOR: u8 = 18;
+const UNVERIFY_COLLECTION_DISCRIMINATOR: u8 = 22;
+const SET_AND_VERIFY_COLLECTION_DISCRIMINATOR: u8 = 25;
+const BURN_NFT_DISCRIMINATOR: u8 = 29;
+const VERIFY_SIZED_COLLECTION_ITEM_DISCRIMINATOR: u8 = 30;
+const UNVERIFY_SIZED_COLLECTION_ITEM_DISCRIMINATOR: u8 = 31;
+const SET_AND_VERIFY_SIZED_COLLECTION_ITEM_DISCRIMINATOR: u8 = 32;
+const CREATE_METADATA_ACCOUNT_V3_DISCRIMINATOR: u8 = 33;
+const BURN_DISCRIMINATOR: u8 = 41;
+const CREATE_DISCRIMINATOR: u8 = 42;
+const MINT_DISCRIMINATOR: u8 = 43;
+const DELEGATE_DISCRIMINATOR: u8 = 44;
+const REVOKE_DISCRIMINATOR: u8 = 45;
+const LOCK_DISCRIMINATOR: u8 = 46;
+const UNLOCK_DISCRIMINATOR: u8 = 47;
+const TRANSFER_DISCRIMINATOR: u8 = 49;
+const UPDATE_DISCRIMINATOR: u8 = 50;
+const VERIFY_DISCRIMINATOR: u8 = 52;
+const UNVERIFY_DISCRIMINATOR: u8 = 53;
+const PRINT_DISCRIMINATOR: u8 = 55;
+
+/// Decoded Token Metadata instructions.  Accounts use the `mpl_token_metadata::instructions`
+/// account structs.  Legacy instructions such as `VerifyCollection` take no args.  Args of the
+/// other instructions use the `mpl_token_metadata::types` enum for the instruction family
+/// (e.g. `VerificationArgs::CollectionV1` for `VerifyCollectionV1`).
+///
+/// Optional accounts that were omitted (passed as the Token Metadata program id) are `None`.
+/// Signer flags in `(Pubkey, bool)` accounts are always `false` because `InstructionBundle` does
+/// not carry signer information.
+#[allow(clippy::large_enum_variant)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum TokenMetadataInstructionData {
+    Burn {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Burn,
+        args: BurnArgs,
+    },
+    BurnNft {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: BurnNft,
+    },
+    Create {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Create,
+        args: CreateArgs,
+    },
+    CreateMetadataAccountV3 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: CreateMetadataAccountV3,
+        args: CreateMetadataAccountV3InstructionArgs,
+    },
+    Delegate {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Delegate,
+        args: DelegateArgs,
+    },
+    Lock {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Lock,
+        args: LockArgs,
+    },
+    Mint {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Mint,
+        args: MintArgs,
+    },
+    Print {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Print,
+        args: PrintArgs,
+    },
+    Revoke {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Revoke,
+        args: RevokeArgs,
+    },
+    SetAndVerifyCollection {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: SetAndVerifyCollection,
+    },
+    SetAndVerifySizedCollectionItem {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: SetAndVerifySizedCollectionItem,
+    },
+    SignMetadata {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: SignMetadata,
+    },
+    Transfer {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Transfer,
+        args: TransferArgs,
+    },
+    Unlock {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Unlock,
+        args: UnlockArgs,
+    },
+    Unverify {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Unverify,
+        args: VerificationArgs,
+    },
+    UnverifyCollection {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: UnverifyCollection,
+    },
+    UnverifySizedCollectionItem {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: UnverifySizedCollectionItem,
+    },
+    Update {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Update,
+        args: UpdateArgs,
+    },
+    UpdateMetadataAccountV2 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: UpdateMetadataAccountV2,
+        args: UpdateMetadataAccountV2InstructionArgs,
+    },
+    Verify {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: Verify,
+        args: VerificationArgs,
+    },
+    VerifyCollection {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: VerifyCollection,
+    },
+    VerifySizedCollectionItem {
+        #[cfg_attr(
+            feature = "serde",
+            serde(serialize_with = "crate::serialize::instruction_accounts")
+        )]
+        accounts: VerifySizedCollectionItem,
+    },
+    /// An instruction that blockbuster does not decode yet.
+    Unknown,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TokenMetadataInstruction {
+    pub discriminator: u8,
+    pub data: TokenMetadataInstructionData,
+}
+
+impl ParseResult for TokenMetadataInstruction {
+    fn result(&self) -> &Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+    fn result_type(&self) -> ProgramParseResult {
+        ProgramParseResult::TokenMetadataInstruction(self)
+    }
+}
+
+/// Decodes Token Metadata instruction data.  `keys` are the instruction's accounts, in order.
+pub fn parse_instruction(
+    keys: &[Pubkey],
+    ix_data: &[u8],
+) -> Result<TokenMetadataInstruction, BlockbusterError> {
+    let (discriminator, args) = ix_data
+        .split_first()
+        .ok_or(BlockbusterError::InstructionParsingError)?;
+
+    // See Token Metadata for account positions:
+    // https://github.com/metaplex-foundation/mpl-token-metadata/blob/main/programs/token-metadata/program/src/instruction/mod.rs
+    let data = match *discriminator {
+        BURN_DISCRIMINATOR => TokenMetadataInstructionData::Burn {
+            accounts: Burn {
+                authority: get_key(keys, 0)?,
+                collection_metadata: get_optional_key(keys, 1, &ID),
+                metadata: get_key(keys, 2)?,
+                edition: get_optional_key(keys, 3, &ID),
+                mint: get_key(keys, 4)?,
+                token: get_key(keys, 5)?,
+                master_edition: get_optional_key(keys, 6, &ID),
+                master_edition_mint: get_optional_key(keys, 7, &ID),
+                master_edition_token: get_optional_key(keys, 8, &ID),
+                edition_marker: get_optional_key(keys, 9, &ID),
+                token_record: get_optional_key(keys, 10, &ID),
+                system_program: get_key(keys, 11)?,
+                sysvar_instructions: get_key(keys, 12)?,
+                spl_token_program: get_key(keys, 13)?,
+            },
+            args: BurnArgs::try_from_slice(args)?,
+        },
+        BURN_NFT_DISCRIMINATOR => TokenMetadataInstructionData::BurnNft {
+            accounts: BurnNft {
+                metadata: get_key(keys, 0)?,
+                owner: get_key(keys, 1)?,
+                mint: get_key(keys, 2)?,
+                token_account: get_key(keys, 3)?,
+                master_edition_account: get_key(keys, 4)?,
+                spl_token_program: get_key(keys, 5)?,
+                collection_metadata: get_optional_key(keys, 6, &ID),
+            },
+        },
+        CREATE_DISCRIMINATOR => TokenMetadataInstructionData::Create {
+            accounts: Create {
+                metadata: get_key(keys, 0)?,
+                master_edition: get_optional_key(keys, 1, &ID),
+                mint: (get_key(keys, 2)?, false),
+                authority: get_key(keys, 3)?,
+                payer: get_key(keys, 4)?,
+                update_authority: (get_key(keys, 5)?, false),
+                system_program: get_key(keys, 6)?,
+                sysvar_instructions: get_key(keys, 7)?,
+                spl_token_program: get_optional_key(keys, 8, &ID),
+            },
+            args: CreateArgs::try_from_slice(args)?,
+        },
+        CREATE_METADATA_ACCOUNT_V3_DISCRIMINATOR => {
+            TokenMetadataInstructionData::CreateMetadataAccountV3 {
+                accounts: CreateMetadataAccountV3 {
+                    metadata: get_key(keys, 0)?,
+                    mint: get_key(keys, 1)?,
+                    mint_authority: get_key(keys, 2)?,
+                    payer: get_key(keys, 3)?,
+                    update_authority: (get_key(keys, 4)?, false),
+                    system_program: get_key(keys, 5)?,
+                    rent: get_optional_key(keys, 6, &ID),
+                },
+                args: CreateMetadataAccountV3InstructionArgs::try_from_slice(args)?,
+            }
+        }
+        DELEGATE_DISCRIMINATOR => TokenMetadataInstructionData::Delegate {
+            accounts: Delegate {
+                delegate_record: get_optional_key(keys, 0, &ID),
+                delegate: get_key(keys, 1)?,
+                metadata: get_key(keys, 2)?,
+                master_edition: get_optional_key(keys, 3, &ID),
+                token_record: get_optional_key(keys, 4, &ID),
+                mint: get_key(keys, 5)?,
+                token: get_optional_key(keys, 6, &ID),
+                authority: get_key(keys, 7)?,
+                payer: get_key(keys, 8)?,
+                system_program: get_key(keys, 9)?,
+                sysvar_instructions: get_key(keys, 10)?,
+                spl_token_program: get_optional_key(keys, 11, &ID),
+                authorization_rules_program: get_optional_key(keys, 12, &ID),
+                authorization_rules: get_optional_key(keys, 13, &ID),
+            },
+            args: DelegateArgs::try_from_slice(args)?,
+        },
+        LOCK_DISCRIMINATOR => TokenMetadataInstructionData::Lock {
+            accounts: Lock {
+                authority: get_key(keys, 0)?,
+                token_owner: get_optional_key(keys, 1, &ID),
+                token: get_key(keys, 2)?,
+                mint: get_key(keys, 3)?,
+                metadata: get_key(keys, 4)?,
+                edition: get_optional_key(keys, 5, &ID),
+                token_record: get_optional_key(keys, 6, &ID),
+                payer: get_key(keys, 7)?,
+                system_program: get_key(keys, 8)?,
+                sysvar_instructions: get_key(keys, 9)?,
+                spl_token_program: get_optional_key(keys, 10, &ID),
+                authorization_rules_program: get_optional_key(keys, 11, &ID),
+                authorization_rules: get_optional_key(keys, 12, &ID),
+            },
+            args: LockArgs::try_from_slice(args)?,
+        },
+        MINT_DISCRIMINATOR => TokenMetadataInstructionData::Mint {
+            accounts: Mint {
+                token: get_key(keys, 0)?,
+                token_owner: get_optional_key(keys, 1, &ID),
+                metadata: get_key(keys, 2)?,
+                master_edition: get_optional_key(keys, 3, &ID),
+                token_record: get_optional_key(keys, 4, &ID),
+                mint: get_key(keys, 5)?,
+                authority: get_key(keys, 6)?,
+                delegate_record: get_optional_key(keys, 7, &ID),
+                payer: get_key(keys, 8)?,
+                system_program: get_key(keys, 9)?,
+                sysvar_instructions: get_key(keys, 10)?,
+                spl_token_program: get_key(keys, 11)?,
+                spl_ata_program: get_key(keys, 12)?,
+                authorization_rules_program: get_optional_key(keys, 13, &ID),
+                authorization_rules: get_optional_key(keys, 14, &ID),
+            },
+            args: MintArgs::try_from_slice(args)?,
+        },
+        PRINT_DISCRIMINATOR => TokenMetadataInstructionData::Print {
+            accounts: Print {
+                edition_metadata: get_key(keys, 0)?,
+                edition: get_key(keys, 1)?,
+                edition_mint: (get_key(keys, 2)?, false),
+                edition_token_account_owner: get_key(keys, 3)?,
+                edition_token_account: get_key(keys, 4)?,
+                edition_mint_authority: get_key(keys, 5)?,
+                edition_token_record: get_optional_key(keys, 6, &ID),
+                master_edition: get_key(keys, 7)?,
+                edition_marker_pda: get_key(keys, 8)?,
+                payer: get_key(keys, 9)?,
+                master_token_account_owner: (get_key(keys, 10)?, false),
+                master_token_account: get_key(keys, 11)?,
+                master_metadata: get_key(keys, 12)?,
+                update_authority: get_key(keys, 13)?,
+                spl_token_program: get_key(keys, 14)?,
+                spl_ata_program: get_key(keys, 15)?,
+                sysvar_instructions: get_key(keys, 16)?,
+                system_program: get_key(keys, 17)?,
+            },
+            args: PrintArgs::try_from_slice(args)?,
+        },
+        REVOKE_DISCRIMINATOR => TokenMetadataInstructionData::Revoke {
+            accounts: Revoke {
+                delegate_record: get_optional_key(keys, 0, &ID),
+                delegate: get_key(keys, 1)?,
+                metadata: get_key(keys, 2)?,
+                master_edition: get_optional_key(keys, 3, &ID),
+                token_record: get_optional_key(keys, 4, &ID),
+                mint: get_key(keys, 5)?,
+                token: get_optional_key(keys, 6, &ID),
+                authority: get_key(keys, 7)?,
+                payer: get_key(keys, 8)?,
+                system_program: get_key(keys, 9)?,
+                sysvar_instructions: get_key(keys, 10)?,
+                spl_token_program: get_optional_key(keys, 11, &ID),
+                authorization_rules_program: get_optional_key(keys, 12, &ID),
+                authorization_rules: get_optional_key(keys, 13, &ID),
+            },
+            args: RevokeArgs::try_from_slice(args)?,
+        },
+        SET_AND_VERIFY_COLLECTION_DISCRIMINATOR => {
+            TokenMetadataInstructionData::SetAndVerifyCollection {
+                accounts: SetAndVerifyCollection {
+                    metadata: get_key(keys, 0)?,
+                    collection_authority: get_key(keys, 1)?,
+                    payer: get_key(keys, 2)?,
+                    update_authority: get_key(keys, 3)?,
+                    collection_mint: get_key(keys, 4)?,
+                    collection: get_key(keys, 5)?,
+                    collection_master_edition_account: get_key(keys, 6)?,
+                    collection_authority_record: get_optional_key(keys, 7, &ID),
+                },
+            }
+        }
+        SET_AND_VERIFY_SIZED_COLLECTION_ITEM_DISCRIMINATOR => {
+            TokenMetadataInstructionData::SetAndVerifySizedCollectionItem {
+                accounts: SetAndVerifySizedCollectionItem {
+                    metadata: get_key(keys, 0)?,
+                    collection_authority: get_key(keys, 1)?,
+                    payer: get_key(keys, 2)?,
+                    update_authority: get_key(keys, 3)?,
+                    collection_mint: get_key(keys, 4)?,
+                    collection: get_key(keys, 5)?,
+                    collection_master_edition_account: get_key(keys, 6)?,
+                    collection_authority_record: get_optional_key(keys, 7, &ID),
+                },
+            }
+        }
+        SIGN_METADATA_DISCRIMINATOR => TokenMetadataInstructionData::SignMetadata {
+            accounts: SignMetadata {
+                metadata: get_key(keys, 0)?,
+                creator: get_key(keys, 1)?,
+            },
+        },
+        TRANSFER_DISCRIMINATOR => TokenMetadataInstructionData::Transfer {
+            accounts: Transfer {
+                token: get_key(keys, 0)?,
+                token_owner: get_key(keys, 1)?,
+                destination_token: get_key(keys, 2)?,
+                destination_owner: get_key(keys, 3)?,
+                mint: get_key(keys, 4)?,
+                metadata: get_key(keys, 5)?,
+                edition: get_optional_key(keys, 6, &ID),
+                token_record: get_optional_key(keys, 7, &ID),
+                destination_token_record: get_optional_key(keys, 8, &ID),
+                authority: get_key(keys, 9)?,
+                payer: get_key(keys, 10)?,
+                system_program: get_key(keys, 11)?,
+                sysvar_instructions: get_key(keys, 12)?,
+                spl_token_program: get_key(keys, 13)?,
+                spl_ata_program: get_key(keys, 14)?,
+                authorization_rules_program: get_optional_key(keys, 15, &ID),
+                authorization_rules: get_optional_key(keys, 16, &ID),
+            },
+            args: TransferArgs::try_from_slice(args)?,
+        },
+        UNLOCK_DISCRIMINATOR => TokenMetadataInstructionData::Unlock {
+            accounts: Unlock {
+                authority: get_key(keys, 0)?,
+                token_owner: get_optional_key(keys, 1, &ID),
+                token: get_key(keys, 2)?,
+                mint: get_key(keys, 3)?,
+                metadata: get_key(keys, 4)?,
+                edition: get_optional_key(keys, 5, &ID),
+                token_record: get_optional_key(keys, 6, &ID),
+                payer: get_key(keys, 7)?,
+                system_program: get_key(keys, 8)?,
+                sysvar_instructions: get_key(keys, 9)?,
+                spl_token_program: get_optional_key(keys, 10, &ID),
+                authorization_rules_program: get_optional_key(keys, 11, &ID),
+                authorization_rules: get_optional_key(keys, 12, &ID),
+            },
+            args: UnlockArgs::try_from_slice(args)?,
+        },
+        UNVERIFY_DISCRIMINATOR => TokenMetadataInstructionData::Unverify {
+            accounts: Unverify {
+                authority: get_key(keys, 0)?,
+                delegate_record: get_optional_key(keys, 1, &ID),
+                metadata: get_key(keys, 2)?,
+                collection_mint: get_optional_key(keys, 3, &ID),
+                collection_metadata: get_optional_key(keys, 4, &ID),
+                system_program: get_key(keys, 5)?,
+                sysvar_instructions: get_key(keys, 6)?,
+            },
+            args: VerificationArgs::try_from_slice(args)?,
+        },
+        UNVERIFY_COLLECTION_DISCRIMINATOR => TokenMetadataInstructionData::UnverifyCollection {
+            accounts: UnverifyCollection {
+                metadata: get_key(keys, 0)?,
+                collection_authority: get_key(keys, 1)?,
+                collection_mint: get_key(keys, 2)?,
+                collection: get_key(keys, 3)?,
+                collection_master_edition_account: get_key(keys, 4)?,
+                collection_authority_record: get_optional_key(keys, 5, &ID),
+            },
+        },
+        UNVERIFY_SIZED_COLLECTION_ITEM_DISCRIMINATOR => {
+            TokenMetadataInstructionData::UnverifySizedCollectionItem {
+                accounts: UnverifySizedCollectionItem {
+                    metadata: get_key(keys, 0)?,
+                    collection_authority: get_key(keys, 1)?,
+                    payer: get_key(keys, 2)?,
+                    collection_mint: get_key(keys, 3)?,
+                    collection: get_key(keys, 4)?,
+                    collection_master_edition_account: get_key(keys, 5)?,
+                    collection_authority_record: get_optional_key(keys, 6, &ID),
+                },
+            }
+        }
+        UPDATE_DISCRIMINATOR => TokenMetadataInstructionData::Update {
+            accounts: Update {
+                authority: get_key(keys, 0)?,
+                delegate_record: get_optional_key(keys, 1, &ID),
+                token: get_optional_key(keys, 2, &ID),
+                mint: get_key(keys, 3)?,
+                metadata: get_key(keys, 4)?,
+                edition: get_optional_key(keys, 5, &ID),
+                payer: get_key(keys, 6)?,
+                system_program: get_key(keys, 7)?,
+                sysvar_instructions: get_key(keys, 8)?,
+                authorization_rules_program: get_optional_key(keys, 9, &ID),
+                authorization_rules: get_optional_key(keys, 10, &ID),
+            },
+            args: UpdateArgs::try_from_slice(args)?,
+        },
+        UPDATE_METADATA_ACCOUNT_V2_DISCRIMINATOR => {
+            TokenMetadataInstructionData::UpdateMetadataAccountV2 {
+                accounts: UpdateMetadataAccountV2 {
+                    metadata: get_key(keys, 0)?,
+                    update_authority: get_key(keys, 1)?,
+                },
+                args: UpdateMetadataAccountV2InstructionArgs::try_from_slice(args)?,
+            }
+        }
+        VERIFY_DISCRIMINATOR => TokenMetadataInstructionData::Verify {
+            accounts: Verify {
+                authority: get_key(keys, 0)?,
+                delegate_record: get_optional_key(keys, 1, &ID),
+                metadata: get_key(keys, 2)?,
+                collection_mint: get_optional_key(keys, 3, &ID),
+                collection_metadata: get_optional_key(keys, 4, &ID),
+                collection_master_edition: get_optional_key(keys, 5, &ID),
+                system_program: get_key(keys, 6)?,
+                sysvar_instructions: get_key(keys, 7)?,
+            },
+            args: VerificationArgs::try_from_slice(args)?,
+        },
+        VERIFY_COLLECTION_DISCRIMINATOR => TokenMetadataInstructionData::VerifyCollection {
+            accounts: VerifyCollection {
+                metadata: get_key(keys, 0)?,
+                collection_authority: get_key(keys, 1)?,
+                payer: get_key(keys, 2)?,
+                collection_mint: get_key(keys, 3)?,
+                collection: get_key(keys, 4)?,
+                collection_master_edition_account: get_key(keys, 5)?,
+                collection_authority_record: get_optional_key(keys, 6, &ID),
+            },
+        },
+        VERIFY_SIZED_COLLECTION_ITEM_DISCRIMINATOR => {
+            TokenMetadataInstructionData::VerifySizedCollectionItem {
+                accounts: VerifySizedCollectionItem {
+                    metadata: get_key(keys, 0)?,
+                    collection_authority: get_key(keys, 1)?,
+                    payer: get_key(keys, 2)?,
+                    collection_mint: get_key(keys, 3)?,
+                    collection: get_key(keys, 4)?,
+                    collection_master_edition_account: get_key(keys, 5)?,
+                    collection_authority_record: get_optional_key(keys, 6, &ID),
+                },
+            }
+        }
+        _ => TokenMetadataInstructionData::Unknown,
+    };
+
+    Ok(TokenMetadataInstruction {
+        discriminator: *discriminator,
+        data,
+    })
+}
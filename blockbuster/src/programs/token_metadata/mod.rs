@@ -1,5 +1,9 @@
+pub mod instruction;
+pub mod normalize;
+pub mod pda;
 use crate::{
     error::BlockbusterError,
+    instruction::InstructionBundle,
     program_handler::{ParseResult, ProgramParser},
     programs::ProgramParseResult,
 };
@@ -7,7 +11,8 @@ use borsh::BorshDeserialize;
 use mpl_token_metadata::{
     accounts::{
         CollectionAuthorityRecord, DeprecatedMasterEditionV1, Edition, EditionMarker,
-        MasterEdition, Metadata, UseAuthorityRecord,
+        EditionMarkerV2, HolderDelegateRecord, MasterEdition, Metadata, MetadataDelegateRecord,
+        TokenOwnedEscrow, TokenRecord, UseAuthorityRecord,
     },
     types::Key,
 };
@@ -21,6 +26,7 @@ pubkeys!(
 );
 
 #[allow(clippy::large_enum_variant)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TokenMetadataAccountData {
     EditionV1(Edition),
     MasterEditionV1(DeprecatedMasterEditionV1),
@@ -29,9 +35,15 @@ pub enum TokenMetadataAccountData {
     EditionMarker(EditionMarker),
     UseAuthorityRecord(UseAuthorityRecord),
     CollectionAuthorityRecord(CollectionAuthorityRecord),
+    EditionMarkerV2(EditionMarkerV2),
+    TokenOwnedEscrow(TokenOwnedEscrow),
+    TokenRecord(TokenRecord),
+    MetadataDelegateRecord(MetadataDelegateRecord),
+    HolderDelegateRecord(HolderDelegateRecord),
     EmptyAccount,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TokenMetadataAccountState {
     pub key: Key,
     pub data: TokenMetadataAccountData,
@@ -64,7 +76,7 @@ impl ProgramParser for TokenMetadataParser {
     }
 
     fn handles_instructions(&self) -> bool {
-        false
+        true
     }
 
     fn handle_account(
@@ -133,6 +145,51 @@ impl ProgramParser for TokenMetadataParser {
                     data: TokenMetadataAccountData::CollectionAuthorityRecord(account),
                 }
             }
+            Key::EditionMarkerV2 => {
+                #[allow(deprecated)]
+                let account: EditionMarkerV2 = try_from_slice_unchecked(account_data)?;
+
+                TokenMetadataAccountState {
+                    key: account.key,
+                    data: TokenMetadataAccountData::EditionMarkerV2(account),
+                }
+            }
+            Key::TokenOwnedEscrow => {
+                #[allow(deprecated)]
+                let account: TokenOwnedEscrow = try_from_slice_unchecked(account_data)?;
+
+                TokenMetadataAccountState {
+                    key: account.key,
+                    data: TokenMetadataAccountData::TokenOwnedEscrow(account),
+                }
+            }
+            Key::TokenRecord => {
+                #[allow(deprecated)]
+                let account: TokenRecord = try_from_slice_unchecked(account_data)?;
+
+                TokenMetadataAccountState {
+                    key: account.key,
+                    data: TokenMetadataAccountData::TokenRecord(account),
+                }
+            }
+            Key::MetadataDelegate => {
+                #[allow(deprecated)]
+                let account: MetadataDelegateRecord = try_from_slice_unchecked(account_data)?;
+
+                TokenMetadataAccountState {
+                    key: account.key,
+                    data: TokenMetadataAccountData::MetadataDelegateRecord(account),
+                }
+            }
+            Key::HolderDelegate => {
+                #[allow(deprecated)]
+                let account: HolderDelegateRecord = try_from_slice_unchecked(account_data)?;
+
+                TokenMetadataAccountState {
+                    key: account.key,
+                    data: TokenMetadataAccountData::HolderDelegateRecord(account),
+                }
+            }
             Key::MetadataV1 => {
                 let account = Metadata::safe_deserialize(account_data)?;
 
@@ -151,4 +208,19 @@ impl ProgramParser for TokenMetadataParser {
 
         Ok(Box::new(token_metadata_account_state))
     }
+
+    fn handle_instruction(
+        &self,
+        bundle: &InstructionBundle,
+    ) -> Result<Box<(dyn ParseResult + 'static)>, BlockbusterError> {
+        let ix_data = match bundle.instruction {
+            Some(cix) => cix.data.as_ref(),
+            _ => return Err(BlockbusterError::DeserializationError),
+        };
+
+        Ok(Box::new(instruction::parse_instruction(
+            bundle.keys,
+            ix_data,
+        )?))
+    }
 }
@@ -0,0 +1,89 @@
+use mpl_token_metadata::{accounts::Metadata, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
+
+use super::{TokenMetadataAccountData, TokenMetadataAccountState};
+
+/// What can be told about a metadata URI without fetching it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum UriStatus {
+    Valid,
+    Empty,
+    /// The URI fills the whole on-chain field, so it was likely cut short when it was set.
+    Truncated,
+    /// The URI has no scheme, or contains whitespace or control characters.
+    Invalid,
+}
+
+/// `Metadata` strings with their null padding removed.  The raw strings remain available on the
+/// `Metadata` this was built from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NormalizedMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub uri_status: UriStatus,
+    /// Whether the name or symbol fills its whole on-chain field and may have been truncated.
+    pub name_or_symbol_truncated: bool,
+}
+
+impl NormalizedMetadata {
+    pub fn new(metadata: &Metadata) -> Self {
+        let name = trim_padding(&metadata.name);
+        let symbol = trim_padding(&metadata.symbol);
+        let uri = trim_padding(&metadata.uri);
+
+        NormalizedMetadata {
+            uri_status: uri_status(uri),
+            name_or_symbol_truncated: name.len() >= MAX_NAME_LENGTH
+                || symbol.len() >= MAX_SYMBOL_LENGTH,
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            uri: uri.to_string(),
+        }
+    }
+}
+
+impl TokenMetadataAccountState {
+    /// Normalized strings of a `MetadataV1` account, or `None` for other accounts.
+    pub fn normalized_metadata(&self) -> Option<NormalizedMetadata> {
+        match &self.data {
+            TokenMetadataAccountData::MetadataV1(metadata) => {
+                Some(NormalizedMetadata::new(metadata))
+            }
+            _ => None,
+        }
+    }
+}
+
+// Legacy accounts pad strings to their maximum length with `\0`.  Strings in this crate are
+// already valid UTF-8, since borsh rejects anything else.
+fn trim_padding(value: &str) -> &str {
+    value.trim_end_matches('\0')
+}
+
+fn uri_status(uri: &str) -> UriStatus {
+    if uri.is_empty() {
+        return UriStatus::Empty;
+    }
+    if uri.chars().any(|c| c.is_whitespace() || c.is_control()) || !has_scheme(uri) {
+        return UriStatus::Invalid;
+    }
+    if uri.len() >= MAX_URI_LENGTH {
+        return UriStatus::Truncated;
+    }
+    UriStatus::Valid
+}
+
+// An RFC 3986 scheme: a letter followed by letters, digits, `+`, `-` or `.`, then `:`.
+fn has_scheme(uri: &str) -> bool {
+    match uri.split_once(':') {
+        Some((scheme, _)) => {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        }
+        None => false,
+    }
+}
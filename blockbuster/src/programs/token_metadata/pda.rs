@@ -0,0 +1,92 @@
+use crate::error::BlockbusterError;
+use mpl_token_metadata::accounts::{
+    CollectionAuthorityRecord, EditionMarker, EditionMarkerV2, MasterEdition, Metadata, TokenRecord,
+};
+use solana_sdk::pubkey::Pubkey;
+
+use super::{TokenMetadataAccountData, TokenMetadataAccountState};
+
+/// Number of editions tracked by each `EditionMarker` account.
+pub const EDITION_MARKER_BIT_SIZE: u64 = 248;
+
+pub fn find_metadata_address(mint: &Pubkey) -> Pubkey {
+    Metadata::find_pda(mint).0
+}
+
+/// Address of the `MasterEdition` or `Edition` account of `mint`.
+pub fn find_edition_address(mint: &Pubkey) -> Pubkey {
+    MasterEdition::find_pda(mint).0
+}
+
+/// Address of the `EditionMarker` recording whether `edition` of `master_mint` was printed.
+pub fn find_edition_marker_address(master_mint: &Pubkey, edition: u64) -> Pubkey {
+    EditionMarker::find_pda(
+        master_mint,
+        &(edition / EDITION_MARKER_BIT_SIZE).to_string(),
+    )
+    .0
+}
+
+pub fn find_edition_marker_v2_address(master_mint: &Pubkey) -> Pubkey {
+    EditionMarkerV2::find_pda(master_mint).0
+}
+
+/// Address of the `TokenRecord` of `token_account`, a programmable NFT token account of `mint`.
+pub fn find_token_record_address(mint: &Pubkey, token_account: &Pubkey) -> Pubkey {
+    TokenRecord::find_pda(mint, token_account).0
+}
+
+pub fn find_collection_authority_record_address(
+    mint: &Pubkey,
+    collection_authority: &Pubkey,
+) -> Pubkey {
+    CollectionAuthorityRecord::find_pda(mint, collection_authority).0
+}
+
+/// Returns `InvalidPda` if `address` is not `expected`.
+pub fn check_address(address: &Pubkey, expected: Pubkey) -> Result<(), BlockbusterError> {
+    if *address == expected {
+        Ok(())
+    } else {
+        Err(BlockbusterError::InvalidPda(*address, expected))
+    }
+}
+
+impl TokenMetadataAccountState {
+    /// The address Token Metadata derives for this account, when its seeds are `mint` and values
+    /// stored in the account.
+    ///
+    /// Returns `None` for accounts derived from seeds that are not stored in them, such as token
+    /// records and edition markers; use the `find_*` functions for those.
+    pub fn expected_address(&self, mint: &Pubkey) -> Option<Pubkey> {
+        match &self.data {
+            TokenMetadataAccountData::MetadataV1(_) => Some(find_metadata_address(mint)),
+            TokenMetadataAccountData::EditionV1(_)
+            | TokenMetadataAccountData::MasterEditionV1(_)
+            | TokenMetadataAccountData::MasterEditionV2(_) => Some(find_edition_address(mint)),
+            TokenMetadataAccountData::EditionMarkerV2(_) => {
+                Some(find_edition_marker_v2_address(mint))
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks that `address` is `expected_address(mint)`.  Returns `MintMismatch` if a `Metadata`
+    /// account claims a mint other than `mint`, `InvalidPda` on an address mismatch and
+    /// `PdaNotDerivable` if the expected address cannot be derived.
+    pub fn validate_address(
+        &self,
+        address: &Pubkey,
+        mint: &Pubkey,
+    ) -> Result<(), BlockbusterError> {
+        if let TokenMetadataAccountData::MetadataV1(metadata) = &self.data {
+            if metadata.mint != *mint {
+                return Err(BlockbusterError::MintMismatch(metadata.mint, *mint));
+            }
+        }
+        let expected = self
+            .expected_address(mint)
+            .ok_or(BlockbusterError::PdaNotDerivable)?;
+        check_address(address, expected)
+    }
+}
@@ -0,0 +1,494 @@
+//! `Serialize` implementations for types from other crates that do not derive it, used through
+//! `#[serde(serialize_with = "...")]` on parse result fields.  See the "Serialization" section of
+//! the README for the resulting schema.
+
+use mpl_bubblegum::{
+    types::{BubblegumEventType, LeafSchema, Version},
+    InstructionName, LeafSchemaEvent,
+};
+use mpl_core::instructions as mpl_core_instructions;
+use mpl_token_metadata::instructions as token_metadata_instructions;
+use serde::{
+    ser::{Error, SerializeStruct},
+    Serialize, Serializer,
+};
+use solana_sdk::{program_option::COption, pubkey::Pubkey};
+use spl_account_compression::events::ChangeLogEventV1;
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_group_interface::instruction::TokenGroupInstruction;
+
+/// Renders a pubkey as base58.
+struct Base58<'a>(&'a Pubkey);
+
+impl Serialize for Base58<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self.0)
+    }
+}
+
+fn coption(value: &COption<Pubkey>) -> Option<Base58<'_>> {
+    match value {
+        COption::Some(key) => Some(Base58(key)),
+        COption::None => None,
+    }
+}
+
+pub(crate) fn pubkey<S: Serializer>(key: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+    Base58(key).serialize(serializer)
+}
+
+pub(crate) fn option_pubkey<S: Serializer>(
+    key: &Option<Pubkey>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    key.as_ref().map(Base58).serialize(serializer)
+}
+
+pub(crate) fn vec_pubkey<S: Serializer>(keys: &[Pubkey], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(keys.iter().map(Base58))
+}
+
+/// Hashes carried as a `Pubkey`, rendered as an array of numbers like every other hash.
+pub(crate) fn hash<S: Serializer>(hash: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+    hash.to_bytes().serialize(serializer)
+}
+
+/// Field types of the generated instruction account structs.  Signer flags are dropped since
+/// `InstructionBundle` does not carry them.
+trait AccountKey {
+    fn key(&self) -> Option<Base58<'_>>;
+}
+
+impl AccountKey for Pubkey {
+    fn key(&self) -> Option<Base58<'_>> {
+        Some(Base58(self))
+    }
+}
+
+impl AccountKey for (Pubkey, bool) {
+    fn key(&self) -> Option<Base58<'_>> {
+        Some(Base58(&self.0))
+    }
+}
+
+impl<T: AccountKey> AccountKey for Option<T> {
+    fn key(&self) -> Option<Base58<'_>> {
+        self.as_ref().and_then(AccountKey::key)
+    }
+}
+
+/// Generated instruction account structs, serialized as an object of base58 keys.
+pub(crate) trait InstructionAccounts {
+    fn serialize_accounts<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+/// The struct is destructured without `..`, so a field list that drifts from the upstream struct
+/// fails to compile.
+macro_rules! instruction_accounts {
+    ($module:ident: $($name:ident { $($field:ident),* $(,)? })*) => {$(
+        impl InstructionAccounts for $module::$name {
+            fn serialize_accounts<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let $module::$name { $($field),* } = self;
+                let len = [$(stringify!($field)),*].len();
+                let mut state = serializer.serialize_struct(stringify!($name), len)?;
+                $(state.serialize_field(stringify!($field), &$field.key())?;)*
+                state.end()
+            }
+        }
+    )*};
+}
+
+instruction_accounts!(mpl_core_instructions:
+    AddCollectionPluginV1 { collection, payer, authority, system_program, log_wrapper }
+    AddPluginV1 { asset, collection, payer, authority, system_program, log_wrapper }
+    ApproveCollectionPluginAuthorityV1 { collection, payer, authority, system_program, log_wrapper }
+    ApprovePluginAuthorityV1 { asset, collection, payer, authority, system_program, log_wrapper }
+    BurnCollectionV1 { collection, payer, authority, log_wrapper }
+    BurnV1 { asset, collection, payer, authority, system_program, log_wrapper }
+    CompressV1 { asset, collection, payer, authority, system_program, log_wrapper }
+    CreateCollectionV1 { collection, update_authority, payer, system_program }
+    CreateCollectionV2 { collection, update_authority, payer, system_program }
+    CreateV1 {
+        asset, collection, authority, payer, owner, update_authority, system_program, log_wrapper
+    }
+    CreateV2 {
+        asset, collection, authority, payer, owner, update_authority, system_program, log_wrapper
+    }
+    DecompressV1 { asset, collection, payer, authority, system_program, log_wrapper }
+    RemoveCollectionPluginV1 { collection, payer, authority, system_program, log_wrapper }
+    RemovePluginV1 { asset, collection, payer, authority, system_program, log_wrapper }
+    RevokeCollectionPluginAuthorityV1 { collection, payer, authority, system_program, log_wrapper }
+    RevokePluginAuthorityV1 { asset, collection, payer, authority, system_program, log_wrapper }
+    TransferV1 { asset, collection, payer, authority, new_owner, system_program, log_wrapper }
+    UpdateCollectionPluginV1 { collection, payer, authority, system_program, log_wrapper }
+    UpdateCollectionV1 {
+        collection, payer, authority, new_update_authority, system_program, log_wrapper
+    }
+    UpdatePluginV1 { asset, collection, payer, authority, system_program, log_wrapper }
+    UpdateV1 { asset, collection, payer, authority, system_program, log_wrapper }
+    UpdateV2 { asset, collection, payer, authority, new_collection, system_program, log_wrapper }
+);
+
+instruction_accounts!(token_metadata_instructions:
+    Burn {
+        authority, collection_metadata, metadata, edition, mint, token, master_edition,
+        master_edition_mint, master_edition_token, edition_marker, token_record, system_program,
+        sysvar_instructions, spl_token_program
+    }
+    BurnNft {
+        metadata, owner, mint, token_account, master_edition_account, spl_token_program,
+        collection_metadata
+    }
+    Create {
+        metadata, master_edition, mint, authority, payer, update_authority, system_program,
+        sysvar_instructions, spl_token_program
+    }
+    CreateMetadataAccountV3 {
+        metadata, mint, mint_authority, payer, update_authority, system_program, rent
+    }
+    Delegate {
+        delegate_record, delegate, metadata, master_edition, token_record, mint, token, authority,
+        payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program,
+        authorization_rules
+    }
+    Lock {
+        authority, token_owner, token, mint, metadata, edition, token_record, payer,
+        system_program, sysvar_instructions, spl_token_program, authorization_rules_program,
+        authorization_rules
+    }
+    Mint {
+        token, token_owner, metadata, master_edition, token_record, mint, authority,
+        delegate_record, payer, system_program, sysvar_instructions, spl_token_program,
+        spl_ata_program, authorization_rules_program, authorization_rules
+    }
+    Print {
+        edition_metadata, edition, edition_mint, edition_token_account_owner,
+        edition_token_account, edition_mint_authority, edition_token_record, master_edition,
+        edition_marker_pda, payer, master_token_account_owner, master_token_account,
+        master_metadata, update_authority, spl_token_program, spl_ata_program,
+        sysvar_instructions, system_program
+    }
+    Revoke {
+        delegate_record, delegate, metadata, master_edition, token_record, mint, token, authority,
+        payer, system_program, sysvar_instructions, spl_token_program, authorization_rules_program,
+        authorization_rules
+    }
+    SetAndVerifyCollection {
+        metadata, collection_authority, payer, update_authority, collection_mint, collection,
+        collection_master_edition_account, collection_authority_record
+    }
+    SetAndVerifySizedCollectionItem {
+        metadata, collection_authority, payer, update_authority, collection_mint, collection,
+        collection_master_edition_account, collection_authority_record
+    }
+    SignMetadata { metadata, creator }
+    Transfer {
+        token, token_owner, destination_token, destination_owner, mint, metadata, edition,
+        token_record, destination_token_record, authority, payer, system_program,
+        sysvar_instructions, spl_token_program, spl_ata_program, authorization_rules_program,
+        authorization_rules
+    }
+    Unlock {
+        authority, token_owner, token, mint, metadata, edition, token_record, payer,
+        system_program, sysvar_instructions, spl_token_program, authorization_rules_program,
+        authorization_rules
+    }
+    Unverify {
+        authority, delegate_record, metadata, collection_mint, collection_metadata,
+        system_program, sysvar_instructions
+    }
+    UnverifyCollection {
+        metadata, collection_authority, collection_mint, collection,
+        collection_master_edition_account, collection_authority_record
+    }
+    UnverifySizedCollectionItem {
+        metadata, collection_authority, payer, collection_mint, collection,
+        collection_master_edition_account, collection_authority_record
+    }
+    Update {
+        authority, delegate_record, token, mint, metadata, edition, payer, system_program,
+        sysvar_instructions, authorization_rules_program, authorization_rules
+    }
+    UpdateMetadataAccountV2 { metadata, update_authority }
+    Verify {
+        authority, delegate_record, metadata, collection_mint, collection_metadata,
+        collection_master_edition, system_program, sysvar_instructions
+    }
+    VerifyCollection {
+        metadata, collection_authority, payer, collection_mint, collection,
+        collection_master_edition_account, collection_authority_record
+    }
+    VerifySizedCollectionItem {
+        metadata, collection_authority, payer, collection_mint, collection,
+        collection_master_edition_account, collection_authority_record
+    }
+);
+
+pub(crate) fn instruction_accounts<T, S>(accounts: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: InstructionAccounts,
+    S: Serializer,
+{
+    accounts.serialize_accounts(serializer)
+}
+
+pub(crate) fn instruction_name<S: Serializer>(
+    name: &InstructionName,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(match name {
+        InstructionName::Unknown => "Unknown",
+        InstructionName::MintV1 => "MintV1",
+        InstructionName::Redeem => "Redeem",
+        InstructionName::CancelRedeem => "CancelRedeem",
+        InstructionName::Transfer => "Transfer",
+        InstructionName::Delegate => "Delegate",
+        InstructionName::DecompressV1 => "DecompressV1",
+        InstructionName::Compress => "Compress",
+        InstructionName::Burn => "Burn",
+        InstructionName::CreateTree => "CreateTree",
+        InstructionName::VerifyCreator => "VerifyCreator",
+        InstructionName::UnverifyCreator => "UnverifyCreator",
+        InstructionName::VerifyCollection => "VerifyCollection",
+        InstructionName::UnverifyCollection => "UnverifyCollection",
+        InstructionName::SetAndVerifyCollection => "SetAndVerifyCollection",
+        InstructionName::MintToCollectionV1 => "MintToCollectionV1",
+        InstructionName::SetDecompressibleState => "SetDecompressibleState",
+        InstructionName::UpdateMetadata => "UpdateMetadata",
+        InstructionName::BurnV2 => "BurnV2",
+        InstructionName::CollectV2 => "CollectV2",
+        InstructionName::CreateTreeV2 => "CreateTreeV2",
+        InstructionName::DelegateAndFreezeV2 => "DelegateAndFreezeV2",
+        InstructionName::DelegateV2 => "DelegateV2",
+        InstructionName::FreezeV2 => "FreezeV2",
+        InstructionName::MintV2 => "MintV2",
+        InstructionName::SetCollectionV2 => "SetCollectionV2",
+        InstructionName::SetNonTransferableV2 => "SetNonTransferableV2",
+        InstructionName::ThawAndRevokeV2 => "ThawAndRevokeV2",
+        InstructionName::ThawV2 => "ThawV2",
+        InstructionName::TransferV2 => "TransferV2",
+        InstructionName::UnverifyCreatorV2 => "UnverifyCreatorV2",
+        InstructionName::UpdateAssetDataV2 => "UpdateAssetDataV2",
+        InstructionName::UpdateMetadataV2 => "UpdateMetadataV2",
+        InstructionName::VerifyCreatorV2 => "VerifyCreatorV2",
+    })
+}
+
+#[derive(Serialize)]
+struct LeafSchemaEventView<'a> {
+    event_type: &'a BubblegumEventType,
+    version: &'a Version,
+    schema: &'a LeafSchema,
+    leaf_hash: &'a [u8; 32],
+}
+
+pub(crate) fn leaf_schema_event<S: Serializer>(
+    event: &Option<LeafSchemaEvent>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    event
+        .as_ref()
+        .map(|event| LeafSchemaEventView {
+            event_type: &event.event_type,
+            version: &event.version,
+            schema: &event.schema,
+            leaf_hash: &event.leaf_hash,
+        })
+        .serialize(serializer)
+}
+
+#[derive(Serialize)]
+struct PathNodeView<'a> {
+    node: &'a [u8; 32],
+    index: u32,
+}
+
+#[derive(Serialize)]
+struct ChangeLogEventView<'a> {
+    id: Base58<'a>,
+    path: Vec<PathNodeView<'a>>,
+    seq: u64,
+    index: u32,
+}
+
+pub(crate) fn change_log_event<S: Serializer>(
+    event: &Option<ChangeLogEventV1>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    event
+        .as_ref()
+        .map(|event| ChangeLogEventView {
+            id: Base58(&event.id),
+            path: event
+                .path
+                .iter()
+                .map(|node| PathNodeView {
+                    node: &node.node,
+                    index: node.index,
+                })
+                .collect(),
+            seq: event.seq,
+            index: event.index,
+        })
+        .serialize(serializer)
+}
+
+#[derive(Serialize)]
+struct MintView<'a> {
+    mint_authority: Option<Base58<'a>>,
+    supply: u64,
+    decimals: u8,
+    is_initialized: bool,
+    freeze_authority: Option<Base58<'a>>,
+}
+
+#[derive(Serialize)]
+struct AccountView<'a> {
+    mint: Base58<'a>,
+    owner: Base58<'a>,
+    amount: u64,
+    delegate: Option<Base58<'a>>,
+    state: &'static str,
+    is_native: Option<u64>,
+    delegated_amount: u64,
+    close_authority: Option<Base58<'a>>,
+}
+
+#[derive(Serialize)]
+struct MultisigView<'a> {
+    m: u8,
+    n: u8,
+    is_initialized: bool,
+    signers: Vec<Base58<'a>>,
+}
+
+// spl-token and Token-2022 define identical but distinct state types.
+macro_rules! token_state {
+    ($($state:ident)::+, $mint:ident, $account:ident, $multisig:ident) => {
+        pub(crate) fn $mint<S: Serializer>(
+            mint: &$($state)::+::Mint,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            MintView {
+                mint_authority: coption(&mint.mint_authority),
+                supply: mint.supply,
+                decimals: mint.decimals,
+                is_initialized: mint.is_initialized,
+                freeze_authority: coption(&mint.freeze_authority),
+            }
+            .serialize(serializer)
+        }
+
+        pub(crate) fn $account<S: Serializer>(
+            account: &$($state)::+::Account,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            use $($state)::+::AccountState;
+
+            AccountView {
+                mint: Base58(&account.mint),
+                owner: Base58(&account.owner),
+                amount: account.amount,
+                delegate: coption(&account.delegate),
+                state: match account.state {
+                    AccountState::Uninitialized => "uninitialized",
+                    AccountState::Initialized => "initialized",
+                    AccountState::Frozen => "frozen",
+                },
+                is_native: account.is_native.into(),
+                delegated_amount: account.delegated_amount,
+                close_authority: coption(&account.close_authority),
+            }
+            .serialize(serializer)
+        }
+
+        pub(crate) fn $multisig<S: Serializer>(
+            multisig: &$($state)::+::Multisig,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            MultisigView {
+                m: multisig.m,
+                n: multisig.n,
+                is_initialized: multisig.is_initialized,
+                signers: multisig.signers[..multisig.n as usize]
+                    .iter()
+                    .map(Base58)
+                    .collect(),
+            }
+            .serialize(serializer)
+        }
+    };
+}
+
+token_state!(
+    spl_token::state,
+    spl_token_mint,
+    spl_token_account,
+    spl_token_multisig
+);
+token_state!(
+    spl_token_2022::state,
+    token_2022_mint,
+    token_2022_account,
+    token_2022_multisig
+);
+
+/// Decoded spl-token or Token-2022 instructions, which pack back to their instruction data.
+pub(crate) trait PackedTokenInstruction {
+    fn pack(&self) -> Vec<u8>;
+}
+
+/// Serializes a token program instruction as the Token-2022 `TokenInstruction` it packs to.
+/// Token-2022 encodes every spl-token instruction identically, so this also covers spl-token.
+pub(crate) fn token_instruction<T, S>(instruction: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: PackedTokenInstruction,
+    S: Serializer,
+{
+    spl_token_2022::instruction::TokenInstruction::unpack(&instruction.pack())
+        .map_err(S::Error::custom)?
+        .serialize(serializer)
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+enum TokenGroupInstructionView<'a> {
+    InitializeGroup {
+        update_authority: &'a OptionalNonZeroPubkey,
+        max_size: u32,
+    },
+    UpdateGroupMaxSize {
+        max_size: u32,
+    },
+    UpdateGroupAuthority {
+        new_authority: &'a OptionalNonZeroPubkey,
+    },
+    InitializeMember,
+}
+
+/// Serialized in the camelCase style of the token-metadata interface instructions.
+pub(crate) fn token_group_instruction<S: Serializer>(
+    instruction: &TokenGroupInstruction,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match instruction {
+        TokenGroupInstruction::InitializeGroup(data) => {
+            TokenGroupInstructionView::InitializeGroup {
+                update_authority: &data.update_authority,
+                max_size: data.max_size.into(),
+            }
+        }
+        TokenGroupInstruction::UpdateGroupMaxSize(data) => {
+            TokenGroupInstructionView::UpdateGroupMaxSize {
+                max_size: data.max_size.into(),
+            }
+        }
+        TokenGroupInstruction::UpdateGroupAuthority(data) => {
+            TokenGroupInstructionView::UpdateGroupAuthority {
+                new_authority: &data.new_authority,
+            }
+        }
+        TokenGroupInstruction::InitializeMember(_) => TokenGroupInstructionView::InitializeMember,
+    }
+    .serialize(serializer)
+}
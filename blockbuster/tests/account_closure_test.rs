@@ -0,0 +1,78 @@
+#[cfg(test)]
+use blockbuster::{
+    parsed_programs::Program,
+    program_handler::ProgramParser,
+    programs::{
+        account_closure::{AccountClosureData, AccountClosureParser, ClosedAccountInfo},
+        ProgramParseResult,
+    },
+};
+use helpers::*;
+use solana_sdk::system_program;
+
+mod helpers;
+
+#[test]
+fn test_setup() {
+    let subject = AccountClosureParser {};
+    assert_eq!(subject.key(), system_program::id());
+    assert!(subject.key_match(&system_program::id()));
+    assert!(!subject.handles_account_updates());
+    assert!(!subject.handles_instructions());
+}
+
+#[test]
+fn test_closed_account() {
+    let subject = AccountClosureParser {};
+
+    let pubkey = random_pubkey();
+    for owner in [system_program::id(), random_pubkey()] {
+        let result = subject.handle_account_closure(&pubkey, &owner, 0).unwrap();
+        if let ProgramParseResult::AccountClosure(data) = result.result_type() {
+            assert_eq!(
+                data,
+                &AccountClosureData::ClosedAccountInfo(ClosedAccountInfo { pubkey, owner })
+            );
+        } else {
+            panic!("Unexpected ProgramParseResult variant");
+        }
+    }
+}
+
+#[test]
+fn test_open_account() {
+    let subject = AccountClosureParser {};
+
+    let result = subject
+        .handle_account_closure(&random_pubkey(), &system_program::id(), 1)
+        .unwrap();
+    if let ProgramParseResult::AccountClosure(data) = result.result_type() {
+        assert_eq!(data, &AccountClosureData::EmptyAccount);
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
+
+#[test]
+fn test_parsed_programs() {
+    let programs = Program::all();
+    assert_eq!(programs.len(), 7);
+
+    let closure = Program::from_key(&system_program::id()).unwrap();
+    assert!(matches!(closure, Program::AccountClosure(_)));
+    assert_eq!(closure.key(), system_program::id());
+    assert!(matches!(
+        closure.handle_account(&[]).unwrap().result_type(),
+        ProgramParseResult::Unknown
+    ));
+
+    let bubblegum = Program::from_key(&mpl_bubblegum::ID).unwrap();
+    assert!(bubblegum.handles_instructions());
+    assert!(!bubblegum.handles_account_updates());
+
+    let token = Program::from_key(&spl_token::id()).unwrap();
+    assert!(matches!(token, Program::TokenAccount(_)));
+    assert!(token.handles_account_updates());
+
+    assert!(Program::from_key(&random_pubkey()).is_none());
+}
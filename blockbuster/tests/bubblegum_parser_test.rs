@@ -1,15 +1,35 @@
 #[cfg(test)]
 use blockbuster::{
+    error::BlockbusterError,
+    instruction::InstructionBundle,
     program_handler::ProgramParser,
-    programs::{bubblegum::BubblegumParser, ProgramParseResult},
+    programs::{
+        bubblegum::{BubblegumParser, LeafArgs, Payload},
+        ProgramParseResult,
+    },
 };
 use flatbuffers::FlatBufferBuilder;
 use helpers::*;
 use mpl_bubblegum::{
-    instructions::{MintV1InstructionArgs, TransferInstructionArgs},
-    types::{BubblegumEventType, Creator, LeafSchema, MetadataArgs, TokenProgramVersion, Version},
+    instructions::{
+        BurnV2InstructionArgs, CancelRedeemInstructionArgs, DelegateAndFreezeV2InstructionArgs,
+        DelegateV2InstructionArgs, FreezeV2InstructionArgs, MintV1InstructionArgs,
+        MintV2InstructionArgs, RedeemInstructionArgs, SetCollectionV2InstructionArgs,
+        SetNonTransferableV2InstructionArgs, ThawAndRevokeV2InstructionArgs, ThawV2InstructionArgs,
+        TransferInstructionArgs, TransferV2InstructionArgs, UnverifyCreatorV2InstructionArgs,
+        UpdateAssetDataV2InstructionArgs, UpdateMetadataV2InstructionArgs,
+        VerifyCreatorV2InstructionArgs,
+    },
+    types::{
+        AssetDataSchema, BubblegumEventType, Creator, LeafSchema, MetadataArgs, MetadataArgsV2,
+        TokenProgramVersion, UpdateArgs, Version,
+    },
     LeafSchemaEvent,
 };
+use solana_sdk::{
+    instruction::{CompiledInstruction, Instruction},
+    pubkey::Pubkey,
+};
 use spl_account_compression::{
     events::{AccountCompressionEvent, ChangeLogEvent},
     state::PathNode,
@@ -24,6 +44,20 @@ fn test_setup() {
     assert!(subject.key_match(&mpl_bubblegum::ID));
 }
 
+#[test]
+fn test_short_instruction_data() {
+    let instruction = CompiledInstruction::new_from_raw_parts(0, vec![1, 2, 3], vec![]);
+    let bundle = InstructionBundle {
+        program: mpl_bubblegum::ID,
+        instruction: Some(&instruction),
+        ..Default::default()
+    };
+    assert!(matches!(
+        BubblegumParser.handle_instruction(&bundle),
+        Err(BlockbusterError::InstructionParsingError)
+    ));
+}
+
 #[test]
 fn test_mint() {
     let subject = BubblegumParser {};
@@ -121,7 +155,13 @@ fn test_mint() {
             _ => Err(()),
         };
         assert!(matched.is_ok());
-        assert!(b.payload.is_some());
+        assert!(matches!(
+            b.payload,
+            Some(Payload::MintV1 {
+                tree_creator_or_delegate,
+                ..
+            }) if tree_creator_or_delegate == ix.tree_creator_or_delegate
+        ));
         assert!(b.leaf_update.is_some());
         assert!(b.tree_update.is_some());
     } else {
@@ -208,7 +248,23 @@ fn test_basic_success_parsing() {
     let result = subject.handle_instruction(&ix_b);
 
     if let ProgramParseResult::Bubblegum(b) = result.unwrap().result_type() {
-        assert!(b.payload.is_none());
+        match &b.payload {
+            Some(Payload::Transfer {
+                leaf,
+                tree_authority,
+                leaf_owner,
+                leaf_delegate,
+                ..
+            }) => {
+                assert_eq!(tree_authority, &ix.tree_config);
+                assert_eq!(leaf.root, [0; 32]);
+                assert_eq!(leaf.data_hash, Some([0; 32]));
+                assert_eq!(leaf.asset_data_hash, None);
+                assert_eq!(leaf_owner, &ix.leaf_owner.0);
+                assert_eq!(leaf_delegate, &ix.leaf_delegate.0);
+            }
+            _ => panic!("Unexpected payload"),
+        }
         let matched = match b.instruction {
             mpl_bubblegum::InstructionName::Transfer => Ok(()),
             _ => Err(()),
@@ -220,3 +276,769 @@ fn test_basic_success_parsing() {
         panic!("Unexpected ProgramParseResult variant");
     }
 }
+
+fn random_leaf_schema_v2_event() -> LeafSchemaEvent {
+    LeafSchemaEvent {
+        event_type: BubblegumEventType::LeafSchemaEvent,
+        version: Version::V2,
+        schema: LeafSchema::V2 {
+            id: random_pubkey(),
+            owner: random_pubkey(),
+            delegate: random_pubkey(),
+            nonce: 0,
+            data_hash: [0; 32],
+            creator_hash: [0; 32],
+            collection_hash: [0; 32],
+            asset_data_hash: [0; 32],
+            flags: 0,
+        },
+        leaf_hash: [0; 32],
+    }
+}
+
+fn random_change_log_event() -> AccountCompressionEvent {
+    AccountCompressionEvent::ChangeLog(ChangeLogEvent::new(
+        random_pubkey(),
+        vec![PathNode {
+            node: [0; 32],
+            index: 0,
+        }],
+        0,
+        0,
+    ))
+}
+
+fn account_indexes(accounts: &[Pubkey]) -> Vec<u8> {
+    accounts.iter().enumerate().map(|(i, _)| i as u8).collect()
+}
+
+#[test]
+fn test_mint_v2() {
+    let subject = BubblegumParser {};
+
+    let collection = random_pubkey();
+    let metadata = MetadataArgsV2 {
+        name: "test".to_string(),
+        symbol: "test".to_string(),
+        uri: "www.solana.pos".to_owned(),
+        seller_fee_basis_points: 0,
+        primary_sale_happened: false,
+        is_mutable: false,
+        token_standard: None,
+        creators: vec![Creator {
+            address: random_pubkey(),
+            verified: false,
+            share: 100,
+        }],
+        collection: Some(collection),
+    };
+
+    // Optional accounts that are omitted are replaced by the Bubblegum program id.
+    let ix = mpl_bubblegum::instructions::MintV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        tree_creator_or_delegate: None,
+        collection_authority: Some(random_pubkey()),
+        leaf_owner: random_pubkey(),
+        leaf_delegate: None,
+        merkle_tree: random_pubkey(),
+        core_collection: Some(collection),
+        mpl_core_cpi_signer: Some(random_pubkey()),
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        mpl_core_program: mpl_core::ID,
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(MintV2InstructionArgs {
+        metadata: metadata.clone(),
+        asset_data: None,
+        asset_data_schema: None,
+    });
+    let accounts: Vec<Pubkey> = instruction.accounts.iter().map(|a| a.pubkey).collect();
+    let account_indexes = account_indexes(&accounts);
+
+    let mut fbb1 = FlatBufferBuilder::new();
+    let mut fbb2 = FlatBufferBuilder::new();
+    let mut fbb3 = FlatBufferBuilder::new();
+    let mut fbb4 = FlatBufferBuilder::new();
+
+    let ix_b = build_bubblegum_bundle(
+        &mut fbb1,
+        &mut fbb2,
+        &mut fbb3,
+        &mut fbb4,
+        &accounts,
+        &account_indexes,
+        &instruction.data,
+        random_leaf_schema_v2_event(),
+        random_change_log_event(),
+    );
+
+    let result = subject.handle_instruction(&ix_b);
+
+    if let ProgramParseResult::Bubblegum(b) = result.unwrap().result_type() {
+        assert!(matches!(
+            b.instruction,
+            mpl_bubblegum::InstructionName::MintV2
+        ));
+        assert!(matches!(
+            b.leaf_update.as_ref().map(|l| &l.schema),
+            Some(LeafSchema::V2 { .. })
+        ));
+        assert!(b.tree_update.is_some());
+        match &b.payload {
+            Some(Payload::MintV2 {
+                args,
+                authority,
+                tree_creator_or_delegate,
+                tree_id,
+                leaf_owner,
+                leaf_delegate,
+                ..
+            }) => {
+                assert_eq!(args, &metadata);
+                assert_eq!(authority, &ix.tree_config);
+                assert_eq!(tree_creator_or_delegate, &ix.payer);
+                assert_eq!(tree_id, &ix.merkle_tree);
+                assert_eq!(leaf_owner, &ix.leaf_owner);
+                assert_eq!(leaf_delegate, &ix.leaf_owner);
+            }
+            _ => panic!("Unexpected payload"),
+        }
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
+
+#[test]
+fn test_transfer_v2() {
+    let ix = mpl_bubblegum::instructions::TransferV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        authority: None,
+        leaf_owner: random_pubkey(),
+        leaf_delegate: Some(random_pubkey()),
+        new_leaf_owner: random_pubkey(),
+        merkle_tree: random_pubkey(),
+        core_collection: None,
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(TransferV2InstructionArgs {
+        root: [1; 32],
+        data_hash: [2; 32],
+        creator_hash: [3; 32],
+        asset_data_hash: None,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+    });
+
+    assert_payload(
+        instruction,
+        Payload::TransferV2 {
+            leaf: leaf_args(None),
+            authority: ix.payer,
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_delegate.unwrap(),
+            new_leaf_owner: ix.new_leaf_owner,
+            tree_id: ix.merkle_tree,
+            collection: None,
+        },
+    );
+}
+
+#[test]
+fn test_set_tree_delegate() {
+    let subject = BubblegumParser {};
+
+    let ix = mpl_bubblegum::instructions::SetTreeDelegate {
+        tree_config: random_pubkey(),
+        tree_creator: random_pubkey(),
+        new_tree_delegate: random_pubkey(),
+        merkle_tree: random_pubkey(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let result = parse_instruction(&subject, mpl_bubblegum::ID, &ix.instruction());
+
+    if let ProgramParseResult::Bubblegum(b) = result.unwrap().result_type() {
+        let expected = Payload::SetTreeDelegate {
+            tree_authority: ix.tree_config,
+            tree_creator: ix.tree_creator,
+            new_tree_delegate: ix.new_tree_delegate,
+            tree_id: ix.merkle_tree,
+        };
+        assert!(b.payload == Some(expected));
+        assert!(b.leaf_update.is_none());
+        assert!(b.tree_update.is_none());
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
+
+// Parses `instruction` from a plain bundle, with the accounts in the order the Bubblegum client
+// lays them out, and checks the payload.
+fn assert_payload(instruction: Instruction, expected: Payload) {
+    let result = parse_instruction(&BubblegumParser, mpl_bubblegum::ID, &instruction).unwrap();
+    if let ProgramParseResult::Bubblegum(b) = result.result_type() {
+        assert!(b.payload == Some(expected));
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
+
+fn random_metadata_v2() -> MetadataArgsV2 {
+    MetadataArgsV2 {
+        name: "test".to_string(),
+        symbol: "test".to_string(),
+        uri: "https://example.com/test.json".to_string(),
+        seller_fee_basis_points: 500,
+        primary_sale_happened: false,
+        is_mutable: true,
+        token_standard: None,
+        creators: vec![Creator {
+            address: random_pubkey(),
+            verified: false,
+            share: 100,
+        }],
+        collection: Some(random_pubkey()),
+    }
+}
+
+fn leaf_args(asset_data_hash: Option<[u8; 32]>) -> LeafArgs {
+    LeafArgs {
+        root: [1; 32],
+        data_hash: Some([2; 32]),
+        creator_hash: Some([3; 32]),
+        collection_hash: None,
+        asset_data_hash,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+    }
+}
+
+#[test]
+fn test_burn_v2() {
+    let ix = mpl_bubblegum::instructions::BurnV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        authority: Some(random_pubkey()),
+        leaf_owner: random_pubkey(),
+        leaf_delegate: None,
+        merkle_tree: random_pubkey(),
+        core_collection: Some(random_pubkey()),
+        mpl_core_cpi_signer: Some(random_pubkey()),
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        mpl_core_program: mpl_core::ID,
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(BurnV2InstructionArgs {
+        root: [1; 32],
+        data_hash: [2; 32],
+        creator_hash: [3; 32],
+        asset_data_hash: Some([4; 32]),
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+    });
+
+    assert_payload(
+        instruction,
+        Payload::BurnV2 {
+            leaf: leaf_args(Some([4; 32])),
+            authority: ix.authority.unwrap(),
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_owner,
+            tree_id: ix.merkle_tree,
+            collection: ix.core_collection,
+        },
+    );
+}
+
+#[test]
+fn test_delegate_v2() {
+    let ix = mpl_bubblegum::instructions::DelegateV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        leaf_owner: Some(random_pubkey()),
+        previous_leaf_delegate: None,
+        new_leaf_delegate: random_pubkey(),
+        merkle_tree: random_pubkey(),
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(DelegateV2InstructionArgs {
+        root: [1; 32],
+        data_hash: [2; 32],
+        creator_hash: [3; 32],
+        collection_hash: None,
+        asset_data_hash: None,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+    });
+
+    assert_payload(
+        instruction,
+        Payload::DelegateV2 {
+            leaf: leaf_args(None),
+            leaf_owner: ix.leaf_owner.unwrap(),
+            previous_leaf_delegate: ix.leaf_owner.unwrap(),
+            new_leaf_delegate: ix.new_leaf_delegate,
+            tree_id: ix.merkle_tree,
+        },
+    );
+
+    // The payer is the leaf owner when the leaf owner is omitted.
+    let ix = mpl_bubblegum::instructions::DelegateAndFreezeV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        leaf_owner: None,
+        previous_leaf_delegate: Some(random_pubkey()),
+        new_leaf_delegate: random_pubkey(),
+        merkle_tree: random_pubkey(),
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(DelegateAndFreezeV2InstructionArgs {
+        root: [1; 32],
+        data_hash: [2; 32],
+        creator_hash: [3; 32],
+        collection_hash: None,
+        asset_data_hash: None,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+    });
+
+    assert_payload(
+        instruction,
+        Payload::DelegateAndFreezeV2 {
+            leaf: leaf_args(None),
+            leaf_owner: ix.payer,
+            previous_leaf_delegate: ix.previous_leaf_delegate.unwrap(),
+            new_leaf_delegate: ix.new_leaf_delegate,
+            tree_id: ix.merkle_tree,
+        },
+    );
+}
+
+#[test]
+fn test_freeze_and_thaw_v2() {
+    let ix = mpl_bubblegum::instructions::FreezeV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        authority: None,
+        leaf_owner: random_pubkey(),
+        leaf_delegate: random_pubkey(),
+        merkle_tree: random_pubkey(),
+        core_collection: Some(random_pubkey()),
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(FreezeV2InstructionArgs {
+        root: [1; 32],
+        data_hash: [2; 32],
+        creator_hash: [3; 32],
+        asset_data_hash: None,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+    });
+
+    assert_payload(
+        instruction,
+        Payload::FreezeV2 {
+            leaf: leaf_args(None),
+            authority: ix.payer,
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_delegate,
+            tree_id: ix.merkle_tree,
+            collection: ix.core_collection,
+        },
+    );
+
+    let ix = mpl_bubblegum::instructions::ThawV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        authority: Some(random_pubkey()),
+        leaf_owner: random_pubkey(),
+        leaf_delegate: random_pubkey(),
+        merkle_tree: random_pubkey(),
+        core_collection: None,
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(ThawV2InstructionArgs {
+        root: [1; 32],
+        data_hash: [2; 32],
+        creator_hash: [3; 32],
+        asset_data_hash: None,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+    });
+
+    assert_payload(
+        instruction,
+        Payload::ThawV2 {
+            leaf: leaf_args(None),
+            authority: ix.authority.unwrap(),
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_delegate,
+            tree_id: ix.merkle_tree,
+            collection: None,
+        },
+    );
+
+    let ix = mpl_bubblegum::instructions::ThawAndRevokeV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        leaf_delegate: Some(random_pubkey()),
+        leaf_owner: random_pubkey(),
+        merkle_tree: random_pubkey(),
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(ThawAndRevokeV2InstructionArgs {
+        root: [1; 32],
+        data_hash: [2; 32],
+        creator_hash: [3; 32],
+        collection_hash: None,
+        asset_data_hash: None,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+    });
+
+    assert_payload(
+        instruction,
+        Payload::ThawAndRevokeV2 {
+            leaf: leaf_args(None),
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_delegate.unwrap(),
+            tree_id: ix.merkle_tree,
+        },
+    );
+}
+
+#[test]
+fn test_set_collection_v2() {
+    let metadata = random_metadata_v2();
+    let ix = mpl_bubblegum::instructions::SetCollectionV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        authority: Some(random_pubkey()),
+        new_collection_authority: None,
+        leaf_owner: random_pubkey(),
+        leaf_delegate: Some(random_pubkey()),
+        merkle_tree: random_pubkey(),
+        core_collection: metadata.collection,
+        new_core_collection: Some(random_pubkey()),
+        mpl_core_cpi_signer: random_pubkey(),
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        mpl_core_program: mpl_core::ID,
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(SetCollectionV2InstructionArgs {
+        root: [1; 32],
+        asset_data_hash: None,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+        metadata: metadata.clone(),
+    });
+
+    assert_payload(
+        instruction,
+        Payload::SetCollectionV2 {
+            leaf: LeafArgs {
+                data_hash: None,
+                creator_hash: None,
+                ..leaf_args(None)
+            },
+            metadata,
+            authority: ix.authority.unwrap(),
+            new_collection_authority: ix.authority.unwrap(),
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_delegate.unwrap(),
+            tree_id: ix.merkle_tree,
+            collection: ix.core_collection,
+            new_collection: ix.new_core_collection,
+        },
+    );
+}
+
+#[test]
+fn test_update_metadata_v2() {
+    let metadata = random_metadata_v2();
+    let update_args = UpdateArgs {
+        name: Some("updated".to_string()),
+        symbol: None,
+        uri: None,
+        creators: None,
+        seller_fee_basis_points: None,
+        primary_sale_happened: None,
+        is_mutable: None,
+    };
+    let ix = mpl_bubblegum::instructions::UpdateMetadataV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        authority: Some(random_pubkey()),
+        leaf_owner: random_pubkey(),
+        leaf_delegate: None,
+        merkle_tree: random_pubkey(),
+        core_collection: metadata.collection,
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(UpdateMetadataV2InstructionArgs {
+        root: [1; 32],
+        asset_data_hash: None,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+        current_metadata: metadata.clone(),
+        update_args: update_args.clone(),
+    });
+
+    assert_payload(
+        instruction,
+        Payload::UpdateMetadataV2 {
+            leaf: LeafArgs {
+                data_hash: None,
+                creator_hash: None,
+                ..leaf_args(None)
+            },
+            current_metadata: metadata,
+            update_args,
+            authority: ix.authority.unwrap(),
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_owner,
+            tree_id: ix.merkle_tree,
+            collection: ix.core_collection,
+        },
+    );
+}
+
+#[test]
+fn test_update_asset_data_v2() {
+    let ix = mpl_bubblegum::instructions::UpdateAssetDataV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        authority: None,
+        leaf_owner: random_pubkey(),
+        leaf_delegate: Some(random_pubkey()),
+        merkle_tree: random_pubkey(),
+        core_collection: None,
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(UpdateAssetDataV2InstructionArgs {
+        root: [1; 32],
+        data_hash: [2; 32],
+        creator_hash: [3; 32],
+        previous_asset_data_hash: Some([4; 32]),
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+        new_asset_data: Some(vec![5, 6]),
+        new_asset_data_schema: Some(AssetDataSchema::Binary),
+    });
+
+    assert_payload(
+        instruction,
+        Payload::UpdateAssetDataV2 {
+            leaf: leaf_args(Some([4; 32])),
+            new_asset_data: Some(vec![5, 6]),
+            new_asset_data_schema: Some(AssetDataSchema::Binary),
+            authority: ix.payer,
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_delegate.unwrap(),
+            tree_id: ix.merkle_tree,
+            collection: None,
+        },
+    );
+}
+
+#[test]
+fn test_set_non_transferable_v2() {
+    let ix = mpl_bubblegum::instructions::SetNonTransferableV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        authority: Some(random_pubkey()),
+        leaf_owner: random_pubkey(),
+        leaf_delegate: None,
+        merkle_tree: random_pubkey(),
+        core_collection: random_pubkey(),
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(SetNonTransferableV2InstructionArgs {
+        root: [1; 32],
+        data_hash: [2; 32],
+        creator_hash: [3; 32],
+        asset_data_hash: None,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+    });
+
+    assert_payload(
+        instruction,
+        Payload::SetNonTransferableV2 {
+            leaf: leaf_args(None),
+            authority: ix.authority.unwrap(),
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_owner,
+            tree_id: ix.merkle_tree,
+            collection: ix.core_collection,
+        },
+    );
+}
+
+#[test]
+fn test_creator_verification_v2() {
+    let metadata = random_metadata_v2();
+    let ix = mpl_bubblegum::instructions::VerifyCreatorV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        creator: Some(metadata.creators[0].address),
+        leaf_owner: random_pubkey(),
+        leaf_delegate: Some(random_pubkey()),
+        merkle_tree: random_pubkey(),
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(VerifyCreatorV2InstructionArgs {
+        root: [1; 32],
+        asset_data_hash: None,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+        metadata: metadata.clone(),
+    });
+    let leaf = LeafArgs {
+        data_hash: None,
+        creator_hash: None,
+        ..leaf_args(None)
+    };
+
+    assert_payload(
+        instruction,
+        Payload::CreatorVerificationV2 {
+            leaf: leaf.clone(),
+            metadata: metadata.clone(),
+            creator: metadata.creators[0].address,
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_delegate.unwrap(),
+            tree_id: ix.merkle_tree,
+            verify: true,
+        },
+    );
+
+    // The payer is the creator when the creator is omitted.
+    let ix = mpl_bubblegum::instructions::UnverifyCreatorV2 {
+        tree_config: random_pubkey(),
+        payer: random_pubkey(),
+        creator: None,
+        leaf_owner: random_pubkey(),
+        leaf_delegate: None,
+        merkle_tree: random_pubkey(),
+        log_wrapper: mpl_noop::id(),
+        compression_program: mpl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(UnverifyCreatorV2InstructionArgs {
+        root: [1; 32],
+        asset_data_hash: None,
+        flags: Some(1),
+        nonce: 7,
+        index: 7,
+        metadata: metadata.clone(),
+    });
+
+    assert_payload(
+        instruction,
+        Payload::CreatorVerificationV2 {
+            leaf,
+            metadata,
+            creator: ix.payer,
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_owner,
+            tree_id: ix.merkle_tree,
+            verify: false,
+        },
+    );
+}
+
+#[test]
+fn test_redeem_and_cancel_redeem() {
+    let ix = mpl_bubblegum::instructions::Redeem {
+        tree_config: random_pubkey(),
+        leaf_owner: random_pubkey(),
+        leaf_delegate: random_pubkey(),
+        merkle_tree: random_pubkey(),
+        voucher: random_pubkey(),
+        log_wrapper: spl_noop::id(),
+        compression_program: spl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(RedeemInstructionArgs {
+        root: [1; 32],
+        data_hash: [2; 32],
+        creator_hash: [3; 32],
+        nonce: 7,
+        index: 7,
+    });
+
+    assert_payload(
+        instruction,
+        Payload::Redeem {
+            leaf: LeafArgs {
+                flags: None,
+                ..leaf_args(None)
+            },
+            tree_authority: ix.tree_config,
+            leaf_owner: ix.leaf_owner,
+            leaf_delegate: ix.leaf_delegate,
+            tree_id: ix.merkle_tree,
+            voucher: ix.voucher,
+        },
+    );
+
+    let ix = mpl_bubblegum::instructions::CancelRedeem {
+        tree_config: random_pubkey(),
+        leaf_owner: random_pubkey(),
+        merkle_tree: random_pubkey(),
+        voucher: random_pubkey(),
+        log_wrapper: spl_noop::id(),
+        compression_program: spl_account_compression::id(),
+        system_program: solana_sdk::system_program::id(),
+    };
+    let instruction = ix.instruction(CancelRedeemInstructionArgs { root: [1; 32] });
+
+    assert_payload(
+        instruction,
+        Payload::CancelRedeem {
+            root: Pubkey::new_from_array([1; 32]),
+            tree_authority: ix.tree_config,
+            leaf_owner: ix.leaf_owner,
+            tree_id: ix.merkle_tree,
+            voucher: ix.voucher,
+        },
+    );
+}
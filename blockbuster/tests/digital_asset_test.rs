@@ -0,0 +1,277 @@
+#[cfg(test)]
+use blockbuster::{
+    digital_asset::{AssetCollection, AssetCreator, DigitalAsset},
+    error::BlockbusterError,
+    programs::{
+        bubblegum::{BubblegumInstruction, InstructionName, LeafArgs, Payload},
+        mpl_core_program::{MplCoreAccountData, MplCoreAccountState},
+        token_extensions::extension::{AdditionalMetadata, ShadowMetadata},
+    },
+};
+use helpers::*;
+use mpl_bubblegum::{types::MetadataArgsV2, utils::get_asset_id};
+use mpl_core::{
+    types::{
+        Creator, FreezeDelegate, Key, Plugin, PluginAuthority, PluginType, Royalties, RuleSet,
+        TransferDelegate, UpdateAuthority, VerifiedCreators, VerifiedCreatorsSignature,
+    },
+    IndexableAsset, IndexablePluginSchemaV1,
+};
+use mpl_token_metadata::{
+    accounts::Metadata,
+    types::{Collection, Creator as MetadataCreator, Key as MetadataKey},
+};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use std::collections::HashMap;
+
+mod helpers;
+
+#[test]
+fn test_from_token_metadata() {
+    let creator = random_pubkey();
+    let metadata = Metadata {
+        key: MetadataKey::MetadataV1,
+        update_authority: random_pubkey(),
+        mint: random_pubkey(),
+        name: "name\0\0\0".to_string(),
+        symbol: "SYM\0".to_string(),
+        uri: "https://example.com\0\0".to_string(),
+        seller_fee_basis_points: 500,
+        creators: Some(vec![MetadataCreator {
+            address: creator,
+            verified: true,
+            share: 100,
+        }]),
+        primary_sale_happened: false,
+        is_mutable: true,
+        edition_nonce: None,
+        token_standard: None,
+        collection: Some(Collection {
+            verified: false,
+            key: random_pubkey(),
+        }),
+        uses: None,
+        collection_details: None,
+        programmable_config: None,
+    };
+
+    let asset = DigitalAsset::from(&metadata);
+    assert_eq!(asset.id, Some(metadata.mint));
+    assert_eq!(asset.authorities, vec![metadata.update_authority]);
+    assert_eq!(
+        asset.creators,
+        vec![AssetCreator {
+            address: creator,
+            share: 100,
+            verified: true
+        }]
+    );
+    assert_eq!(asset.royalty_basis_points, 500);
+    assert_eq!(
+        asset.collection,
+        Some(AssetCollection {
+            address: metadata.collection.unwrap().key,
+            verified: false
+        })
+    );
+    assert_eq!(
+        (
+            asset.name.as_str(),
+            asset.symbol.as_str(),
+            asset.uri.as_str()
+        ),
+        ("name", "SYM", "https://example.com")
+    );
+    assert_eq!(asset.owner, None);
+}
+
+#[test]
+fn test_from_bubblegum() {
+    let args = MetadataArgsV2 {
+        name: "cNFT".to_string(),
+        symbol: "C".to_string(),
+        uri: "https://example.com/c.json".to_string(),
+        seller_fee_basis_points: 250,
+        primary_sale_happened: false,
+        is_mutable: true,
+        token_standard: None,
+        creators: vec![],
+        collection: Some(random_pubkey()),
+    };
+    let (tree_creator_or_delegate, leaf_owner, tree_id) =
+        (random_pubkey(), random_pubkey(), random_pubkey());
+    let mut ix = BubblegumInstruction::new(InstructionName::MintV2);
+    ix.payload = Some(Payload::MintV2 {
+        args: args.clone(),
+        asset_data: None,
+        asset_data_schema: None,
+        authority: random_pubkey(),
+        tree_creator_or_delegate,
+        tree_id,
+        leaf_owner,
+        leaf_delegate: leaf_owner,
+    });
+
+    let asset = DigitalAsset::try_from(&ix).unwrap();
+    assert_eq!(asset.owner, Some(leaf_owner));
+    assert_eq!(asset.authorities, vec![tree_creator_or_delegate]);
+    assert_eq!(asset.supply, Some(1));
+    assert_eq!(
+        asset.collection,
+        Some(AssetCollection {
+            address: args.collection.unwrap(),
+            verified: true
+        })
+    );
+
+    let mut ix = BubblegumInstruction::new(InstructionName::Burn);
+    ix.payload = Some(Payload::Burn {
+        leaf: LeafArgs {
+            root: [0; 32],
+            data_hash: Some([0; 32]),
+            creator_hash: Some([0; 32]),
+            collection_hash: None,
+            asset_data_hash: None,
+            flags: None,
+            nonce: 7,
+            index: 7,
+        },
+        tree_authority: random_pubkey(),
+        leaf_owner,
+        leaf_delegate: leaf_owner,
+        tree_id,
+    });
+    let asset = DigitalAsset::try_from(&ix).unwrap();
+    assert!(asset.burnt);
+    assert_eq!(asset.id, Some(get_asset_id(&tree_id, 7)));
+
+    let ix = BubblegumInstruction::new(InstructionName::Transfer);
+    assert!(matches!(
+        DigitalAsset::try_from(&ix),
+        Err(BlockbusterError::InvalidAccountType)
+    ));
+}
+
+#[test]
+fn test_from_mpl_core() {
+    let (owner, collection, delegate) = (random_pubkey(), random_pubkey(), random_pubkey());
+    let creators = random_list_of(2, |_| random_pubkey());
+    let plugin = |index, authority, data| IndexablePluginSchemaV1 {
+        index,
+        offset: 0,
+        authority,
+        data,
+    };
+    let plugins = HashMap::from([
+        (
+            PluginType::Royalties,
+            plugin(
+                0,
+                PluginAuthority::UpdateAuthority,
+                Plugin::Royalties(Royalties {
+                    basis_points: 300,
+                    creators: creators
+                        .iter()
+                        .map(|address| Creator {
+                            address: *address,
+                            percentage: 50,
+                        })
+                        .collect(),
+                    rule_set: RuleSet::None,
+                }),
+            ),
+        ),
+        (
+            PluginType::VerifiedCreators,
+            plugin(
+                1,
+                PluginAuthority::UpdateAuthority,
+                Plugin::VerifiedCreators(VerifiedCreators {
+                    signatures: vec![VerifiedCreatorsSignature {
+                        address: creators[1],
+                        verified: true,
+                    }],
+                }),
+            ),
+        ),
+        (
+            PluginType::FreezeDelegate,
+            plugin(
+                2,
+                PluginAuthority::Owner,
+                Plugin::FreezeDelegate(FreezeDelegate { frozen: true }),
+            ),
+        ),
+        (
+            PluginType::TransferDelegate,
+            plugin(
+                3,
+                PluginAuthority::Address { address: delegate },
+                Plugin::TransferDelegate(TransferDelegate {}),
+            ),
+        ),
+    ]);
+    let state = MplCoreAccountState {
+        key: Key::AssetV1,
+        data: MplCoreAccountData::Asset(IndexableAsset {
+            owner: Some(owner),
+            update_authority: UpdateAuthority::Collection(collection),
+            name: "core".to_string(),
+            uri: "https://example.com/core.json".to_string(),
+            seq: 0,
+            num_minted: None,
+            current_size: None,
+            plugins,
+            unknown_plugins: vec![],
+            external_plugins: vec![],
+            unknown_external_plugins: vec![],
+        }),
+    };
+
+    let asset = DigitalAsset::try_from(&state).unwrap();
+    assert_eq!(asset.id, None);
+    assert_eq!(asset.owner, Some(owner));
+    assert_eq!(asset.delegate, Some(delegate));
+    assert!(asset.frozen);
+    assert_eq!(asset.royalty_basis_points, 300);
+    assert_eq!(
+        asset
+            .creators
+            .iter()
+            .map(|creator| (creator.address, creator.share, creator.verified))
+            .collect::<Vec<_>>(),
+        vec![(creators[0], 50, false), (creators[1], 50, true)]
+    );
+    assert_eq!(
+        asset.collection,
+        Some(AssetCollection {
+            address: collection,
+            verified: true
+        })
+    );
+    assert!(asset.authorities.is_empty());
+
+    let burnt = MplCoreAccountState {
+        key: Key::Uninitialized,
+        data: MplCoreAccountData::EmptyAccount,
+    };
+    assert!(DigitalAsset::try_from(&burnt).unwrap().burnt);
+}
+
+#[test]
+fn test_from_token_extensions_metadata() {
+    let (mint, update_authority) = (random_pubkey(), random_pubkey());
+    let metadata = ShadowMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(update_authority)).unwrap(),
+        mint: mint.to_string(),
+        name: "t22".to_string(),
+        symbol: "T".to_string(),
+        uri: "https://example.com/t22.json".to_string(),
+        additional_metadata: AdditionalMetadata::default(),
+    };
+
+    let asset = DigitalAsset::try_from(&metadata).unwrap();
+    assert_eq!(asset.id, Some(mint));
+    assert_eq!(asset.authorities, vec![update_authority]);
+    assert_eq!(asset.name, "t22");
+}
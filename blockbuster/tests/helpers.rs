@@ -3,6 +3,7 @@
 use blockbuster::{
     error::BlockbusterError,
     instruction::{InstructionBundle, IxPair},
+    program_handler::{ParseResult, ProgramParser},
 };
 use borsh::ser::BorshSerialize;
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
@@ -15,7 +16,10 @@ use plerkle_serialization::{
 };
 use rand::Rng;
 use solana_geyser_plugin_interface::geyser_plugin_interface::ReplicaAccountInfo;
-use solana_sdk::{instruction::CompiledInstruction, pubkey::Pubkey};
+use solana_sdk::{
+    instruction::{CompiledInstruction, Instruction},
+    pubkey::Pubkey,
+};
 use solana_transaction_status::{
     EncodedConfirmedTransactionWithStatusMeta, InnerInstruction, InnerInstructions,
 };
@@ -75,6 +79,35 @@ where
     data
 }
 
+/// Parses `instruction` with `parser` as a top-level instruction of `program`, with its accounts
+/// in the order the instruction lists them.
+pub fn parse_instruction(
+    parser: &dyn ProgramParser,
+    program: Pubkey,
+    instruction: &Instruction,
+) -> Result<Box<dyn ParseResult>, BlockbusterError> {
+    parse_instruction_with_inner(parser, program, instruction, None)
+}
+
+/// Like [`parse_instruction`], with `inner_ix` as the instruction's inner instructions.
+pub fn parse_instruction_with_inner(
+    parser: &dyn ProgramParser,
+    program: Pubkey,
+    instruction: &Instruction,
+    inner_ix: Option<&[IxPair]>,
+) -> Result<Box<dyn ParseResult>, BlockbusterError> {
+    let keys: Vec<Pubkey> = instruction.accounts.iter().map(|a| a.pubkey).collect();
+    let compiled = CompiledInstruction::new_from_raw_parts(0, instruction.data.clone(), vec![]);
+    let bundle = InstructionBundle {
+        program,
+        instruction: Some(&compiled),
+        inner_ix,
+        keys: &keys,
+        ..Default::default()
+    };
+    parser.handle_instruction(&bundle)
+}
+
 pub fn build_random_instruction<'a>(
     fbb: &mut FlatBufferBuilder<'a>,
     accounts_number_in_transaction: usize,
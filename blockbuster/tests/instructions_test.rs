@@ -2,7 +2,8 @@
 mod helpers;
 use anchor_lang::AnchorDeserialize;
 use blockbuster::{
-    instruction::{order_instructions, InstructionBundle},
+    error::BlockbusterError,
+    instruction::{order_instructions, order_versioned_instructions, InstructionBundle},
     program_handler::ProgramParser,
     programs::{
         bubblegum::{BubblegumParser, LeafSchemaEvent, Payload},
@@ -13,6 +14,15 @@ use flatbuffers::FlatBufferBuilder;
 use helpers::*;
 use plerkle_serialization::root_as_transaction_info;
 use rand::prelude::IteratorRandom;
+use solana_sdk::{
+    hash::Hash,
+    instruction::CompiledInstruction,
+    message::{
+        v0::{self, LoadedAddresses, MessageAddressTableLookup},
+        MessageHeader, VersionedMessage,
+    },
+};
+use solana_transaction_status::{InnerInstruction, InnerInstructions, TransactionStatusMeta};
 use spl_account_compression::events::{
     AccountCompressionEvent::{self},
     ApplicationDataEvent, ApplicationDataEventV1, ChangeLogEvent, ChangeLogEventV1,
@@ -39,10 +49,11 @@ fn test_filter() {
         &account_keys,
         &message_instructions,
         &meta_inner_instructions,
-    );
+    )
+    .unwrap();
 
-    for (ib, _inner) in res.iter() {
-        let public_key_matches = hs.contains(&ib.0);
+    for ix in res.iter() {
+        let public_key_matches = hs.contains(&ix.program);
         assert!(public_key_matches);
     }
 
@@ -51,10 +62,185 @@ fn test_filter() {
         &account_keys,
         &message_instructions,
         &meta_inner_instructions,
-    );
+    )
+    .unwrap();
     assert_eq!(res.len(), 0);
 }
 
+#[test]
+fn test_stack_height() {
+    let outer_program = random_pubkey();
+    let account_keys = vec![
+        outer_program,
+        mpl_bubblegum::ID,
+        spl_noop::id(),
+        random_pubkey(),
+    ];
+    let message_instructions = vec![CompiledInstruction::new_from_raw_parts(0, vec![], vec![])];
+    // Bubblegum logs through noop, then the outer program calls another program.
+    let inner_instructions = |heights: [Option<u32>; 4]| {
+        vec![InnerInstructions {
+            index: 0,
+            instructions: [1, 2, 3, 2]
+                .into_iter()
+                .zip(heights)
+                .map(|(program_id_index, stack_height)| InnerInstruction {
+                    instruction: CompiledInstruction::new_from_raw_parts(
+                        program_id_index,
+                        vec![],
+                        vec![],
+                    ),
+                    stack_height,
+                })
+                .collect(),
+        }]
+    };
+    let programs = HashSet::from([mpl_bubblegum::ID]);
+
+    let meta_inner_instructions = inner_instructions([Some(2), Some(3), Some(2), Some(3)]);
+    let res = order_instructions(
+        &programs,
+        &account_keys,
+        &message_instructions,
+        &meta_inner_instructions,
+    )
+    .unwrap();
+    assert_eq!(res.len(), 1);
+    let ix = &res[0];
+    assert_eq!(ix.program, mpl_bubblegum::ID);
+    assert_eq!(ix.outer_index, 0);
+    assert_eq!(ix.inner_index, Some(0));
+    assert_eq!(ix.stack_height, Some(2));
+    assert_eq!(ix.parent_program, Some(outer_program));
+    assert_eq!(
+        ix.inner_ix.iter().map(|(pid, _)| *pid).collect::<Vec<_>>(),
+        vec![spl_noop::id()]
+    );
+
+    // Without stack heights, everything up to the next Bubblegum call is a child.
+    let meta_inner_instructions = inner_instructions([None; 4]);
+    let res = order_instructions(
+        &programs,
+        &account_keys,
+        &message_instructions,
+        &meta_inner_instructions,
+    )
+    .unwrap();
+    assert_eq!(res[0].stack_height, None);
+    assert_eq!(res[0].inner_ix.len(), 3);
+}
+
+#[test]
+fn test_versioned_account_keys() {
+    let payer = random_pubkey();
+    let outer_program = random_pubkey();
+    let loaded_writable = random_pubkey();
+    let message = VersionedMessage::V0(v0::Message {
+        header: MessageHeader {
+            num_required_signatures: 1,
+            num_readonly_signed_accounts: 0,
+            num_readonly_unsigned_accounts: 1,
+        },
+        account_keys: vec![payer, outer_program],
+        recent_blockhash: Hash::default(),
+        // The outer program invokes Bubblegum, which is loaded from a lookup table.
+        instructions: vec![CompiledInstruction::new_from_raw_parts(
+            1,
+            vec![],
+            vec![0, 2],
+        )],
+        address_table_lookups: vec![MessageAddressTableLookup {
+            account_key: random_pubkey(),
+            writable_indexes: vec![0],
+            readonly_indexes: vec![1],
+        }],
+    });
+    let meta = TransactionStatusMeta {
+        inner_instructions: Some(vec![InnerInstructions {
+            index: 0,
+            instructions: vec![InnerInstruction {
+                instruction: CompiledInstruction::new_from_raw_parts(3, vec![], vec![2]),
+                stack_height: Some(2),
+            }],
+        }]),
+        loaded_addresses: LoadedAddresses {
+            writable: vec![loaded_writable],
+            readonly: vec![mpl_bubblegum::ID],
+        },
+        ..TransactionStatusMeta::default()
+    };
+
+    let (account_keys, res) =
+        order_versioned_instructions(&HashSet::from([mpl_bubblegum::ID]), &message, &meta).unwrap();
+    assert_eq!(
+        account_keys.keys(),
+        &[payer, outer_program, loaded_writable, mpl_bubblegum::ID]
+    );
+    assert_eq!(
+        (0..4)
+            .map(|i| account_keys.is_signer(i))
+            .collect::<Vec<_>>(),
+        vec![true, false, false, false]
+    );
+    assert_eq!(
+        (0..4)
+            .map(|i| account_keys.is_writable(i))
+            .collect::<Vec<_>>(),
+        vec![true, false, true, false]
+    );
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].program, mpl_bubblegum::ID);
+    assert_eq!(res[0].parent_program, Some(outer_program));
+}
+
+#[test]
+fn test_invalid_program_id_index() {
+    let account_keys = vec![random_pubkey(), mpl_bubblegum::ID];
+    let message_instructions = vec![CompiledInstruction::new_from_raw_parts(0, vec![], vec![])];
+    let meta_inner_instructions = vec![InnerInstructions {
+        index: 0,
+        instructions: vec![
+            InnerInstruction {
+                instruction: CompiledInstruction::new_from_raw_parts(1, vec![], vec![]),
+                stack_height: Some(2),
+            },
+            InnerInstruction {
+                instruction: CompiledInstruction::new_from_raw_parts(7, vec![], vec![]),
+                stack_height: Some(2),
+            },
+        ],
+    }];
+
+    let err = order_instructions(
+        &HashSet::from([mpl_bubblegum::ID]),
+        &account_keys,
+        &message_instructions,
+        &meta_inner_instructions,
+    )
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        BlockbusterError::InvalidProgramIdIndex {
+            index: 7,
+            key_count: 2,
+            outer_index: 0,
+            inner_index: Some(1),
+        }
+    ));
+
+    let message_instructions = vec![CompiledInstruction::new_from_raw_parts(2, vec![], vec![])];
+    let err =
+        order_instructions(&HashSet::new(), &account_keys, &message_instructions, &[]).unwrap_err();
+    assert!(matches!(
+        err,
+        BlockbusterError::InvalidProgramIdIndex {
+            index: 2,
+            inner_index: None,
+            ..
+        }
+    ));
+}
+
 fn prepare_fixture<'a>(fbb: FlatBufferBuilder<'a>, fixture: &'a str) -> FlatBufferBuilder<'a> {
     println!("{:?}", env::current_dir());
     let name = fixture.to_string();
@@ -77,15 +263,16 @@ fn helium_nested() {
         &account_keys,
         &message_instructions,
         &meta_inner_instructions,
-    );
+    )
+    .unwrap();
 
     let _ix = 0;
 
-    let contains = res.iter().any(|(ib, _inner)| ib.0 == mpl_bubblegum::ID);
+    let contains = res.iter().any(|ix| ix.program == mpl_bubblegum::ID);
     assert!(contains, "Must containe bgum at hoisted root");
     let subject = BubblegumParser {};
-    for (outer_ix, inner_ix) in res.into_iter() {
-        let (program, instruction) = outer_ix;
+    for ordered in res.iter() {
+        let (program, instruction) = ordered.ix_pair();
         // let ix_accounts = instruction.accounts.iter().collect::<Vec<_>>();
         let ix_account_len = instruction.accounts.len();
         // let _max = ix_accounts.iter().max().copied().unwrap_or(0) as usize;
@@ -104,7 +291,7 @@ fn helium_nested() {
             txn_id: "",
             program,
             instruction: Some(instruction),
-            inner_ix: inner_ix.as_deref(),
+            inner_ix: Some(&ordered.inner_ix),
             keys: ix_accounts.as_slice(),
             slot,
         };
@@ -121,6 +308,7 @@ fn helium_nested() {
             Some(Payload::MintV1 {
                 args: _,
                 authority: _,
+                tree_creator_or_delegate: _,
                 tree_id: _,
             }),
         ) = (
@@ -148,34 +336,33 @@ fn test_double_mint() {
         &account_keys,
         &message_instructions,
         &meta_inner_instructions,
-    );
+    )
+    .unwrap();
     assert_eq!(ix.len(), 2);
-    let contains = ix.iter().filter(|(ib, _inner)| ib.0 == mpl_bubblegum::ID);
+    let contains = ix.iter().filter(|ix| ix.program == mpl_bubblegum::ID);
     let mut count = 0;
-    contains.for_each(|(_pk, cix)| {
+    contains.for_each(|ordered| {
         count += 1;
-        if let Some(inner) = &cix {
-            println!("{}", inner.len());
-            for ii in inner {
-                println!("pp{} {:?}", count, ii.0);
-            }
-            println!("------");
-            let cl = AccountCompressionEvent::try_from_slice(&inner[1].1.data).unwrap();
-            if let AccountCompressionEvent::ApplicationData(ApplicationDataEvent::V1(
-                ApplicationDataEventV1 { application_data },
-            )) = cl
-            {
-                let lse = LeafSchemaEvent::try_from_slice(&application_data).unwrap();
-                println!("1 pp{} NONCE {:?}\n end", count, lse.schema.nonce());
-            }
-            let cl = AccountCompressionEvent::try_from_slice(&inner[3].1.data).unwrap();
-            if let AccountCompressionEvent::ChangeLog(ChangeLogEvent::V1(ChangeLogEventV1 {
-                id,
-                ..
-            })) = cl
-            {
-                println!("2 pp{} Merkle Tree {:?} \n end", count, id);
-            }
+        let inner = &ordered.inner_ix;
+        println!("{}", inner.len());
+        for ii in inner {
+            println!("pp{} {:?}", count, ii.0);
+        }
+        println!("------");
+        let cl = AccountCompressionEvent::try_from_slice(&inner[1].1.data).unwrap();
+        if let AccountCompressionEvent::ApplicationData(ApplicationDataEvent::V1(
+            ApplicationDataEventV1 { application_data },
+        )) = cl
+        {
+            let lse = LeafSchemaEvent::try_from_slice(&application_data).unwrap();
+            println!("1 pp{} NONCE {:?}\n end", count, lse.schema.nonce());
+        }
+        let cl = AccountCompressionEvent::try_from_slice(&inner[3].1.data).unwrap();
+        if let AccountCompressionEvent::ChangeLog(ChangeLogEvent::V1(ChangeLogEventV1 {
+            id, ..
+        })) = cl
+        {
+            println!("2 pp{} Merkle Tree {:?} \n end", count, id);
         }
     });
     assert_eq!(count, 2);
@@ -195,31 +382,30 @@ fn test_double_tree() {
         &account_keys,
         &message_instructions,
         &meta_inner_instructions,
-    );
-    let contains = ix.iter().filter(|(ib, _inner)| ib.0 == mpl_bubblegum::ID);
+    )
+    .unwrap();
+    let contains = ix.iter().filter(|ix| ix.program == mpl_bubblegum::ID);
     let mut count = 0;
-    contains.for_each(|(_pk, cix)| {
-        if let Some(inner) = &cix {
-            for ii in inner {
-                println!("pp{} {:?}", count, ii.0);
-            }
-            println!("------");
-            let cl = AccountCompressionEvent::try_from_slice(&inner[1].1.data).unwrap();
-            if let AccountCompressionEvent::ApplicationData(ApplicationDataEvent::V1(
-                ApplicationDataEventV1 { application_data },
-            )) = cl
-            {
-                let lse = LeafSchemaEvent::try_from_slice(&application_data).unwrap();
-                println!("1 pp{} NONCE {:?}\n end", count, lse.schema.nonce());
-            }
-            let cl = AccountCompressionEvent::try_from_slice(&inner[3].1.data).unwrap();
-            if let AccountCompressionEvent::ChangeLog(ChangeLogEvent::V1(ChangeLogEventV1 {
-                id,
-                ..
-            })) = cl
-            {
-                println!("2 pp{} Merkle Tree {:?} \n end", count, id);
-            }
+    contains.for_each(|ordered| {
+        let inner = &ordered.inner_ix;
+        for ii in inner {
+            println!("pp{} {:?}", count, ii.0);
+        }
+        println!("------");
+        let cl = AccountCompressionEvent::try_from_slice(&inner[1].1.data).unwrap();
+        if let AccountCompressionEvent::ApplicationData(ApplicationDataEvent::V1(
+            ApplicationDataEventV1 { application_data },
+        )) = cl
+        {
+            let lse = LeafSchemaEvent::try_from_slice(&application_data).unwrap();
+            println!("1 pp{} NONCE {:?}\n end", count, lse.schema.nonce());
+        }
+        let cl = AccountCompressionEvent::try_from_slice(&inner[3].1.data).unwrap();
+        if let AccountCompressionEvent::ChangeLog(ChangeLogEvent::V1(ChangeLogEventV1 {
+            id, ..
+        })) = cl
+        {
+            println!("2 pp{} Merkle Tree {:?} \n end", count, id);
         }
         count += 1;
     });
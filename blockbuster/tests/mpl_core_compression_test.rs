@@ -0,0 +1,167 @@
+#[cfg(test)]
+use blockbuster::{
+    error::BlockbusterError,
+    program_handler::ProgramParser,
+    programs::{
+        mpl_core_program::{
+            compression::{hash_compression_proof, rebuild_hashed_asset},
+            MplCoreAccountData, MplCoreParser,
+        },
+        ProgramParseResult,
+    },
+};
+use borsh::BorshSerialize;
+use helpers::*;
+use mpl_core::{
+    accounts::HashedAssetV1,
+    instructions::{TransferV1, TransferV1InstructionArgs},
+    types::{
+        CompressionProof, FreezeDelegate, HashablePluginSchema, Key, Plugin, PluginAuthority,
+        PluginType, Royalties, RuleSet, UpdateAuthority,
+    },
+};
+use solana_sdk::{instruction::CompiledInstruction, pubkey::Pubkey};
+
+mod helpers;
+
+fn random_compression_proof() -> CompressionProof {
+    CompressionProof {
+        owner: random_pubkey(),
+        update_authority: UpdateAuthority::Address(random_pubkey()),
+        name: "name".to_string(),
+        uri: "https://example.com".to_string(),
+        seq: 3,
+        plugins: vec![
+            HashablePluginSchema {
+                index: 1,
+                authority: PluginAuthority::UpdateAuthority,
+                plugin: Plugin::Royalties(Royalties {
+                    basis_points: 500,
+                    creators: vec![],
+                    rule_set: RuleSet::None,
+                }),
+            },
+            HashablePluginSchema {
+                index: 0,
+                authority: PluginAuthority::Owner,
+                plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: false }),
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_hashed_asset_account() {
+    let hash = random_pubkey().to_bytes();
+    let data = HashedAssetV1 {
+        key: Key::HashedAssetV1,
+        hash,
+    }
+    .try_to_vec()
+    .unwrap();
+
+    let result = MplCoreParser.handle_account(&data).unwrap();
+    if let ProgramParseResult::MplCore(state) = result.result_type() {
+        assert_eq!(state.key, Key::HashedAssetV1);
+        match &state.data {
+            MplCoreAccountData::HashedAsset(hashed_asset) => assert_eq!(hashed_asset.hash, hash),
+            _ => panic!("Unexpected MplCoreAccountData variant"),
+        }
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
+
+#[test]
+fn test_rebuild_hashed_asset() {
+    let proof = random_compression_proof();
+    let hashed_asset = HashedAssetV1 {
+        key: Key::HashedAssetV1,
+        hash: hash_compression_proof(&proof).unwrap(),
+    };
+
+    let asset = rebuild_hashed_asset(&hashed_asset, &proof).unwrap();
+    assert_eq!(asset.owner, Some(proof.owner));
+    assert_eq!(asset.update_authority, proof.update_authority);
+    assert_eq!(asset.name, proof.name);
+    assert_eq!(asset.uri, proof.uri);
+    assert_eq!(asset.seq, 3);
+    assert_eq!(asset.plugins.len(), 2);
+    assert_eq!(asset.plugins[&PluginType::FreezeDelegate].index, 0);
+    assert_eq!(
+        asset.plugins[&PluginType::Royalties].authority,
+        PluginAuthority::UpdateAuthority
+    );
+
+    // Plugin order in the proof does not affect the hash.
+    let mut reordered = proof.clone();
+    reordered.plugins.reverse();
+    assert_eq!(
+        hash_compression_proof(&reordered).unwrap(),
+        hashed_asset.hash
+    );
+
+    let mut tampered = proof;
+    tampered.seq += 1;
+    assert!(matches!(
+        rebuild_hashed_asset(&hashed_asset, &tampered),
+        Err(BlockbusterError::InvalidCompressionProof)
+    ));
+}
+
+// Hash computed by keccak-hashing hand-encoded borsh bytes of the base asset
+// `[AssetV1, owner, Address(update_authority), "name", "https://example.com", Some(3)]`
+// and of each plugin, per MPL Core's `HashedAssetSchema` layout.
+const FIXED_PROOF_HASH: &str = "13c6cdd0af2e42ebdda6ab1b70594b63376a15e07a91423f6d9448fb903a568e";
+
+#[test]
+fn test_rebuild_hashed_asset_fixed_vector() {
+    let mut proof = random_compression_proof();
+    proof.owner = Pubkey::new_from_array([1; 32]);
+    proof.update_authority = UpdateAuthority::Address(Pubkey::new_from_array([2; 32]));
+
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&FIXED_PROOF_HASH[i * 2..i * 2 + 2], 16).unwrap();
+    }
+    let hashed_asset = HashedAssetV1 {
+        key: Key::HashedAssetV1,
+        hash,
+    };
+
+    assert_eq!(hash_compression_proof(&proof).unwrap(), hash);
+    let asset = rebuild_hashed_asset(&hashed_asset, &proof).unwrap();
+    assert_eq!(asset.owner, Some(Pubkey::new_from_array([1; 32])));
+    assert_eq!(asset.seq, 3);
+}
+
+#[test]
+fn test_compression_proof_event() {
+    let keys = random_list_of(3, |_| random_pubkey());
+    let transfer = TransferV1 {
+        asset: keys[0],
+        collection: None,
+        payer: keys[1],
+        authority: None,
+        new_owner: keys[2],
+        system_program: None,
+        log_wrapper: Some(mpl_noop::id()),
+    }
+    .instruction(TransferV1InstructionArgs {
+        compression_proof: None,
+    });
+
+    let proof = random_compression_proof();
+    let noop = CompiledInstruction::new_from_raw_parts(0, proof.try_to_vec().unwrap(), vec![]);
+    let inner_ix = [(mpl_noop::id(), &noop)];
+
+    let result =
+        parse_instruction_with_inner(&MplCoreParser, mpl_core::ID, &transfer, Some(&inner_ix))
+            .unwrap();
+    if let ProgramParseResult::MplCoreInstruction(ix) = result.result_type() {
+        assert_eq!(ix.compression_event.as_ref(), Some(&proof));
+        assert_eq!(ix.compression_proof(), Some(&proof));
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
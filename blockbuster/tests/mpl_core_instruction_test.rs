@@ -0,0 +1,151 @@
+#[cfg(test)]
+use blockbuster::{
+    program_handler::{ParseResult, ProgramParser},
+    programs::{
+        mpl_core_program::{instruction::MplCoreInstructionData, MplCoreParser},
+        ProgramParseResult,
+    },
+};
+use helpers::*;
+use mpl_core::{
+    instructions::{
+        AddPluginV1, AddPluginV1InstructionArgs, CreateV1, CreateV1InstructionArgs, TransferV1,
+        TransferV1InstructionArgs,
+    },
+    types::{DataState, FreezeDelegate, Plugin, PluginAuthority},
+};
+use solana_sdk::instruction::Instruction;
+
+mod helpers;
+
+fn instruction_data(result: &dyn ParseResult) -> &MplCoreInstructionData {
+    match result.result_type() {
+        ProgramParseResult::MplCoreInstruction(ix) => &ix.data,
+        _ => panic!("Unexpected ProgramParseResult variant"),
+    }
+}
+
+#[test]
+fn test_setup() {
+    let subject = MplCoreParser;
+    assert_eq!(subject.key(), mpl_core::ID);
+    assert!(subject.handles_instructions());
+    assert!(subject.handles_account_updates());
+}
+
+#[test]
+fn test_create_v1() {
+    let keys = random_list_of(4, |_| random_pubkey());
+    let instruction = CreateV1 {
+        asset: keys[0],
+        collection: None,
+        authority: None,
+        payer: keys[1],
+        owner: Some(keys[2]),
+        update_authority: None,
+        system_program: keys[3],
+        log_wrapper: None,
+    }
+    .instruction(CreateV1InstructionArgs {
+        data_state: DataState::AccountState,
+        name: "name".to_string(),
+        uri: "https://example.com".to_string(),
+        plugins: None,
+    });
+
+    let result = parse_instruction(&MplCoreParser, mpl_core::ID, &instruction).unwrap();
+    match instruction_data(result.as_ref()) {
+        MplCoreInstructionData::CreateV1 { accounts, args } => {
+            assert_eq!(accounts.asset, keys[0]);
+            assert_eq!(accounts.collection, None);
+            assert_eq!(accounts.payer, keys[1]);
+            assert_eq!(accounts.owner, Some(keys[2]));
+            assert_eq!(accounts.log_wrapper, None);
+            assert_eq!(args.data_state, DataState::AccountState);
+            assert_eq!(args.name, "name");
+            assert_eq!(args.uri, "https://example.com");
+            assert_eq!(args.plugins, None);
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_transfer_v1() {
+    let keys = random_list_of(5, |_| random_pubkey());
+    let instruction = TransferV1 {
+        asset: keys[0],
+        collection: Some(keys[1]),
+        payer: keys[2],
+        authority: Some(keys[3]),
+        new_owner: keys[4],
+        system_program: None,
+        log_wrapper: None,
+    }
+    .instruction(TransferV1InstructionArgs {
+        compression_proof: None,
+    });
+
+    let result = parse_instruction(&MplCoreParser, mpl_core::ID, &instruction).unwrap();
+    match instruction_data(result.as_ref()) {
+        MplCoreInstructionData::TransferV1 { accounts, args } => {
+            assert_eq!(accounts.asset, keys[0]);
+            assert_eq!(accounts.collection, Some(keys[1]));
+            assert_eq!(accounts.authority, Some(keys[3]));
+            assert_eq!(accounts.new_owner, keys[4]);
+            assert_eq!(accounts.system_program, None);
+            assert_eq!(args.compression_proof, None);
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_add_plugin_v1() {
+    let keys = random_list_of(3, |_| random_pubkey());
+    let instruction = AddPluginV1 {
+        asset: keys[0],
+        collection: None,
+        payer: keys[1],
+        authority: None,
+        system_program: keys[2],
+        log_wrapper: None,
+    }
+    .instruction(AddPluginV1InstructionArgs {
+        plugin: Plugin::FreezeDelegate(FreezeDelegate { frozen: true }),
+        init_authority: Some(PluginAuthority::Owner),
+    });
+
+    let result = parse_instruction(&MplCoreParser, mpl_core::ID, &instruction).unwrap();
+    match instruction_data(result.as_ref()) {
+        MplCoreInstructionData::AddPluginV1 { accounts, args } => {
+            assert_eq!(accounts.asset, keys[0]);
+            assert_eq!(
+                args.plugin,
+                Plugin::FreezeDelegate(FreezeDelegate { frozen: true })
+            );
+            assert_eq!(args.init_authority, Some(PluginAuthority::Owner));
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_unknown_and_invalid_instructions() {
+    // `Collect` is not decoded.
+    let instruction = Instruction::new_with_bytes(mpl_core::ID, &[19], vec![]);
+    let result = parse_instruction(&MplCoreParser, mpl_core::ID, &instruction).unwrap();
+    if let ProgramParseResult::MplCoreInstruction(ix) = result.result_type() {
+        assert_eq!(ix.discriminator, 19);
+        assert!(matches!(ix.data, MplCoreInstructionData::Unknown));
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+
+    // Transfer with too few accounts.
+    let instruction = Instruction::new_with_bytes(mpl_core::ID, &[14, 0], vec![]);
+    assert!(parse_instruction(&MplCoreParser, mpl_core::ID, &instruction).is_err());
+
+    let instruction = Instruction::new_with_bytes(mpl_core::ID, &[], vec![]);
+    assert!(parse_instruction(&MplCoreParser, mpl_core::ID, &instruction).is_err());
+}
@@ -0,0 +1,356 @@
+#[cfg(test)]
+use blockbuster::{
+    error::BlockbusterError,
+    instruction::InstructionBundle,
+    parser_registry::{ParserRegistry, TransactionParseOptions},
+    programs::{
+        bubblegum::{BubblegumParser, Payload},
+        token_account::TokenProgramAccount,
+        ProgramParseResult,
+    },
+};
+use helpers::*;
+use mpl_bubblegum::{instructions::BurnInstructionArgs, InstructionName};
+use solana_sdk::{
+    instruction::CompiledInstruction,
+    message::{Message, MessageHeader, VersionedMessage},
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::{TransactionError, VersionedTransaction},
+};
+use solana_transaction_status::{
+    InnerInstruction, InnerInstructions, TransactionStatusMeta, VersionedConfirmedBlock,
+    VersionedTransactionWithStatusMeta,
+};
+use spl_token::state::Mint;
+
+mod helpers;
+
+#[test]
+fn test_default_parsers() {
+    let registry = ParserRegistry::with_default_parsers();
+
+    let instruction_programs = registry.instruction_programs();
+    assert_eq!(instruction_programs.len(), 5);
+    assert!(instruction_programs.contains(&mpl_bubblegum::ID));
+    assert!(instruction_programs.contains(&mpl_token_metadata::ID));
+    assert!(instruction_programs.contains(&mpl_core::ID));
+    assert!(instruction_programs.contains(&spl_token::id()));
+    assert!(instruction_programs.contains(&spl_token_2022::id()));
+
+    let account_programs = registry.account_programs();
+    assert_eq!(account_programs.len(), 5);
+    assert!(account_programs.contains(&spl_token::id()));
+    assert!(account_programs.contains(&spl_token_2022::id()));
+    assert!(account_programs.contains(&mpl_token_metadata::ID));
+    assert!(account_programs.contains(&mpl_core::ID));
+    assert!(!account_programs.contains(&mpl_bubblegum::ID));
+
+    assert!(registry.get(&mpl_bubblegum::ID).is_some());
+    assert!(registry.get(&random_pubkey()).is_none());
+}
+
+#[test]
+fn test_register() {
+    let mut registry = ParserRegistry::new();
+    assert!(registry.instruction_programs().is_empty());
+
+    assert!(registry.register(Box::new(BubblegumParser)).is_none());
+    assert!(registry.register(Box::new(BubblegumParser)).is_some());
+    assert_eq!(registry.instruction_programs().len(), 1);
+}
+
+#[test]
+fn test_parse_account() {
+    let registry = ParserRegistry::with_default_parsers();
+
+    let mint = Mint {
+        mint_authority: COption::Some(random_pubkey()),
+        supply: 1,
+        decimals: 0,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    let mut data = vec![0; Mint::LEN];
+    Mint::pack(mint, &mut data).unwrap();
+
+    let result = registry
+        .parse_account(&spl_token::id(), &data)
+        .unwrap()
+        .expect("token program is registered");
+    if let ProgramParseResult::TokenProgramAccount(TokenProgramAccount::Mint(parsed)) =
+        result.result_type()
+    {
+        assert_eq!(parsed, &mint);
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+
+    // Unregistered owners and programs that don't handle account updates are skipped.
+    assert!(registry
+        .parse_account(&random_pubkey(), &data)
+        .unwrap()
+        .is_none());
+    assert!(registry
+        .parse_account(&mpl_bubblegum::ID, &data)
+        .unwrap()
+        .is_none());
+
+    assert!(registry.parse_account(&spl_token::id(), &[0; 3]).is_err());
+}
+
+fn burn_data() -> Vec<u8> {
+    let keys = random_list_of(7, |_| random_pubkey());
+    mpl_bubblegum::instructions::Burn {
+        tree_config: keys[0],
+        leaf_owner: (keys[1], true),
+        leaf_delegate: (keys[2], false),
+        merkle_tree: keys[3],
+        log_wrapper: keys[4],
+        compression_program: keys[5],
+        system_program: keys[6],
+    }
+    .instruction(BurnInstructionArgs {
+        root: [0; 32],
+        data_hash: [0; 32],
+        creator_hash: [0; 32],
+        nonce: 0,
+        index: 0,
+    })
+    .data
+}
+
+#[test]
+fn test_parse_instruction() {
+    let registry = ParserRegistry::with_default_parsers();
+
+    let bundle = InstructionBundle {
+        program: random_pubkey(),
+        ..Default::default()
+    };
+    assert!(registry.parse_instruction(&bundle).unwrap().is_none());
+
+    // Bubblegum requires an outer instruction.
+    let bundle = InstructionBundle {
+        program: mpl_bubblegum::ID,
+        ..Default::default()
+    };
+    assert!(registry.parse_instruction(&bundle).is_err());
+
+    let keys = random_list_of(7, |_| random_pubkey());
+    let ix_data = burn_data();
+    let instruction = CompiledInstruction::new_from_raw_parts(0, ix_data, vec![]);
+    let bundle = InstructionBundle {
+        program: mpl_bubblegum::ID,
+        instruction: Some(&instruction),
+        keys: &keys,
+        ..Default::default()
+    };
+    let result = registry
+        .parse_instruction(&bundle)
+        .unwrap()
+        .expect("bubblegum is registered");
+    if let ProgramParseResult::Bubblegum(b) = result.result_type() {
+        assert!(matches!(b.instruction, InstructionName::Burn));
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
+
+// A transaction whose first instruction is a Bubblegum burn and whose second, to
+// `outer_program`, invokes the token program with invalid data.
+fn transaction_with_instructions(outer_program: Pubkey) -> VersionedTransactionWithStatusMeta {
+    let mut account_keys = random_list_of(7, |_| random_pubkey());
+    account_keys.extend([mpl_bubblegum::ID, outer_program, spl_token::id()]);
+    let signature = Signature::new_unique();
+    VersionedTransactionWithStatusMeta {
+        transaction: VersionedTransaction {
+            signatures: vec![signature],
+            message: VersionedMessage::Legacy(Message {
+                header: MessageHeader {
+                    num_required_signatures: 1,
+                    num_readonly_signed_accounts: 0,
+                    num_readonly_unsigned_accounts: 3,
+                },
+                account_keys,
+                recent_blockhash: Default::default(),
+                instructions: vec![
+                    CompiledInstruction::new_from_raw_parts(7, burn_data(), (0..7).collect()),
+                    CompiledInstruction::new_from_raw_parts(8, vec![], vec![]),
+                ],
+            }),
+        },
+        meta: TransactionStatusMeta {
+            inner_instructions: Some(vec![InnerInstructions {
+                index: 1,
+                instructions: vec![InnerInstruction {
+                    instruction: CompiledInstruction::new_from_raw_parts(9, vec![255], vec![]),
+                    stack_height: Some(2),
+                }],
+            }]),
+            ..TransactionStatusMeta::default()
+        },
+    }
+}
+
+#[test]
+fn test_parse_transaction() {
+    let registry = ParserRegistry::with_default_parsers();
+
+    let outer_program = random_pubkey();
+    let mut transaction = transaction_with_instructions(outer_program);
+
+    let parsed = registry
+        .parse_transaction(&transaction, 10, TransactionParseOptions::default())
+        .unwrap();
+    assert_eq!(parsed.len(), 2);
+
+    assert_eq!(parsed[0].program, mpl_bubblegum::ID);
+    assert_eq!(parsed[0].outer_index, 0);
+    assert_eq!(parsed[0].inner_index, None);
+    let result = parsed[0].result.as_ref().unwrap();
+    if let ProgramParseResult::Bubblegum(b) = result.result_type() {
+        assert!(matches!(b.instruction, InstructionName::Burn));
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+
+    // A bad instruction is reported without failing the whole transaction.
+    assert_eq!(parsed[1].program, spl_token::id());
+    assert_eq!(parsed[1].outer_index, 1);
+    assert_eq!(parsed[1].inner_index, Some(0));
+    assert_eq!(parsed[1].parent_program, Some(outer_program));
+    assert!(parsed[1].result.is_err());
+
+    // Failed transactions are skipped unless requested.
+    transaction.meta.status = Err(TransactionError::AccountInUse);
+    assert!(registry
+        .parse_transaction(&transaction, 10, TransactionParseOptions::default())
+        .unwrap()
+        .is_empty());
+    let options = TransactionParseOptions {
+        include_failed: true,
+    };
+    assert_eq!(
+        registry
+            .parse_transaction(&transaction, 10, options)
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_parse_transaction_account_indices() {
+    let registry = ParserRegistry::with_default_parsers();
+
+    // Burn accounts listed in a different order than the message's account keys.
+    let mut transaction = transaction_with_instructions(random_pubkey());
+    let account_keys = transaction
+        .transaction
+        .message
+        .static_account_keys()
+        .to_vec();
+    if let VersionedMessage::Legacy(message) = &mut transaction.transaction.message {
+        message.instructions[0].accounts = vec![3, 4, 5, 6, 0, 1, 2];
+    }
+
+    let parsed = registry
+        .parse_transaction(&transaction, 10, TransactionParseOptions::default())
+        .unwrap();
+    let result = parsed[0].result.as_ref().unwrap();
+    match result.result_type() {
+        ProgramParseResult::Bubblegum(b) => match &b.payload {
+            Some(Payload::Burn {
+                leaf_owner,
+                tree_id,
+                ..
+            }) => {
+                assert_eq!(*leaf_owner, account_keys[4]);
+                assert_eq!(*tree_id, account_keys[6]);
+            }
+            _ => panic!("Unexpected payload"),
+        },
+        _ => panic!("Unexpected ProgramParseResult variant"),
+    }
+
+    // An account index past the end of the account keys fails only that instruction.
+    if let VersionedMessage::Legacy(message) = &mut transaction.transaction.message {
+        message.instructions[0].accounts[1] = 20;
+    }
+    let parsed = registry
+        .parse_transaction(&transaction, 10, TransactionParseOptions::default())
+        .unwrap();
+    assert_eq!(parsed.len(), 2);
+    assert!(matches!(
+        parsed[0].result,
+        Err(BlockbusterError::InvalidAccountIndex {
+            index: 20,
+            key_count: 10,
+            outer_index: 0,
+            inner_index: None,
+        })
+    ));
+}
+
+#[test]
+fn test_parse_block() {
+    let registry = ParserRegistry::with_default_parsers();
+
+    let unregistered = VersionedTransactionWithStatusMeta {
+        transaction: VersionedTransaction {
+            signatures: vec![Signature::new_unique()],
+            message: VersionedMessage::Legacy(Message {
+                account_keys: vec![random_pubkey(), random_pubkey()],
+                instructions: vec![CompiledInstruction::new_from_raw_parts(1, vec![], vec![])],
+                ..Message::default()
+            }),
+        },
+        meta: TransactionStatusMeta::default(),
+    };
+    let mut malformed = transaction_with_instructions(random_pubkey());
+    if let VersionedMessage::Legacy(message) = &mut malformed.transaction.message {
+        message.account_keys.truncate(8);
+    }
+    let block = VersionedConfirmedBlock {
+        previous_blockhash: String::new(),
+        blockhash: String::new(),
+        parent_slot: 99,
+        transactions: vec![
+            transaction_with_instructions(random_pubkey()),
+            unregistered,
+            malformed.clone(),
+        ],
+        rewards: vec![],
+        block_time: Some(1_700_000_000),
+        block_height: None,
+    };
+
+    let parsed = registry.parse_block(&block, 100, TransactionParseOptions::default());
+    assert_eq!(parsed.len(), 2);
+
+    assert_eq!(parsed[0].slot, 100);
+    assert_eq!(parsed[0].parent_slot, 99);
+    assert_eq!(parsed[0].block_time, Some(1_700_000_000));
+    assert_eq!(parsed[0].transaction_index, 0);
+    assert_eq!(
+        parsed[0].signature,
+        block.transactions[0].transaction.signatures[0]
+    );
+    assert_eq!(parsed[0].instructions.as_ref().unwrap().len(), 2);
+
+    // The malformed transaction is reported without failing the block.
+    assert_eq!(parsed[1].transaction_index, 2);
+    assert_eq!(parsed[1].signature, malformed.transaction.signatures[0]);
+    assert!(matches!(
+        parsed[1].instructions,
+        Err(BlockbusterError::InvalidProgramIdIndex {
+            index: 8,
+            outer_index: 1,
+            inner_index: None,
+            ..
+        })
+    ));
+}
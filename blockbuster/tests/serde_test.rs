@@ -0,0 +1,206 @@
+#![cfg(feature = "serde")]
+#[cfg(test)]
+use blockbuster::{
+    program_handler::ProgramParser,
+    programs::{
+        account_closure::{AccountClosureData, ClosedAccountInfo},
+        bubblegum::{BubblegumInstruction, InstructionName, LeafArgs, Payload},
+        mpl_core_program,
+        token_account::TokenAccountParser,
+        token_metadata,
+    },
+};
+use helpers::*;
+use mpl_core::instructions::{UpdateCollectionV1, UpdateCollectionV1InstructionArgs};
+use mpl_token_metadata::instructions::{SignMetadata, VerifyCollection};
+use serde_json::{json, Value};
+use solana_sdk::{program_option::COption, program_pack::Pack, pubkey::Pubkey};
+use spl_token::{
+    instruction::transfer,
+    state::{Mint, Multisig},
+};
+
+mod helpers;
+
+#[test]
+fn test_pubkeys_are_base58() {
+    let (pubkey, owner) = (random_pubkey(), random_pubkey());
+    let data = AccountClosureData::ClosedAccountInfo(ClosedAccountInfo { pubkey, owner });
+
+    assert_eq!(
+        serde_json::to_value(data).unwrap(),
+        json!({
+            "ClosedAccountInfo": { "pubkey": pubkey.to_string(), "owner": owner.to_string() }
+        })
+    );
+}
+
+#[test]
+fn test_token_program_account() {
+    let mint_authority = random_pubkey();
+    let mint = Mint {
+        mint_authority: COption::Some(mint_authority),
+        supply: 100,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    let mut data = vec![0; Mint::LEN];
+    Mint::pack(mint, &mut data).unwrap();
+
+    let result = TokenAccountParser.handle_account(&data).unwrap();
+    assert_eq!(
+        serde_json::to_value(result.result_type()).unwrap(),
+        json!({
+            "TokenProgramAccount": {
+                "Mint": {
+                    "mint_authority": mint_authority.to_string(),
+                    "supply": 100,
+                    "decimals": 6,
+                    "is_initialized": true,
+                    "freeze_authority": null
+                }
+            }
+        })
+    );
+
+    let signers = random_list_of(2, |_| random_pubkey());
+    let mut multisig = Multisig {
+        m: 1,
+        n: 2,
+        is_initialized: true,
+        ..Default::default()
+    };
+    multisig.signers[..2].copy_from_slice(&signers);
+    let mut data = vec![0; Multisig::LEN];
+    Multisig::pack(multisig, &mut data).unwrap();
+
+    let result = TokenAccountParser.handle_account(&data).unwrap();
+    let value = serde_json::to_value(result.result_type()).unwrap();
+    assert_eq!(
+        value["TokenProgramAccount"]["Multisig"]["signers"],
+        json!([signers[0].to_string(), signers[1].to_string()])
+    );
+}
+
+#[test]
+fn test_token_program_instruction() {
+    let (source, destination, owner) = (random_pubkey(), random_pubkey(), random_pubkey());
+    let ix = transfer(&spl_token::id(), &source, &destination, &owner, &[], 42).unwrap();
+
+    let result = parse_instruction(&TokenAccountParser, spl_token::id(), &ix).unwrap();
+    let value = &serde_json::to_value(result.result_type()).unwrap()["TokenProgramInstruction"];
+    assert_eq!(
+        value["instruction"],
+        json!({ "transfer": { "amount": 42 } })
+    );
+    assert_eq!(value["accounts"]["source"], json!(source.to_string()));
+    assert_eq!(value["accounts"]["mint"], json!(null));
+}
+
+#[test]
+fn test_bubblegum_instruction() {
+    let (leaf_owner, leaf_delegate, tree_id) = (random_pubkey(), random_pubkey(), random_pubkey());
+    let mut ix = BubblegumInstruction::new(InstructionName::Burn);
+    ix.payload = Some(Payload::Burn {
+        leaf: LeafArgs {
+            root: [1; 32],
+            data_hash: None,
+            creator_hash: None,
+            collection_hash: None,
+            asset_data_hash: None,
+            flags: None,
+            nonce: 3,
+            index: 3,
+        },
+        tree_authority: random_pubkey(),
+        leaf_owner,
+        leaf_delegate,
+        tree_id,
+    });
+
+    let value = serde_json::to_value(ix).unwrap();
+    assert_eq!(value["instruction"], json!("Burn"));
+    assert_eq!(value["tree_update"], json!(null));
+    assert_eq!(
+        value["payload"]["Burn"]["leaf_owner"],
+        json!(leaf_owner.to_string())
+    );
+    assert_eq!(value["payload"]["Burn"]["leaf"]["nonce"], json!(3));
+}
+
+#[test]
+fn test_bubblegum_cancel_redeem_root_is_bytes() {
+    let mut ix = BubblegumInstruction::new(InstructionName::CancelRedeem);
+    ix.payload = Some(Payload::CancelRedeem {
+        root: Pubkey::new_from_array([1; 32]),
+        tree_authority: random_pubkey(),
+        leaf_owner: random_pubkey(),
+        tree_id: random_pubkey(),
+        voucher: random_pubkey(),
+    });
+
+    let value = serde_json::to_value(ix).unwrap();
+    assert_eq!(value["payload"]["CancelRedeem"]["root"], json!(vec![1; 32]));
+}
+
+// Instruction data serializes as `{ "<Variant>": { "accounts": { .. }, .. } }`.
+fn serialized_account_count(data: &Value) -> usize {
+    let variant = data.as_object().unwrap().values().next().unwrap();
+    variant["accounts"].as_object().unwrap().len()
+}
+
+#[test]
+fn test_instruction_accounts_are_complete() {
+    let keys = random_list_of(7, |_| random_pubkey());
+    let token_metadata_instructions = [
+        SignMetadata {
+            metadata: keys[0],
+            creator: keys[1],
+        }
+        .instruction(),
+        VerifyCollection {
+            metadata: keys[0],
+            collection_authority: keys[1],
+            payer: keys[2],
+            collection_mint: keys[3],
+            collection: keys[4],
+            collection_master_edition_account: keys[5],
+            collection_authority_record: Some(keys[6]),
+        }
+        .instruction(),
+    ];
+    for instruction in token_metadata_instructions {
+        let result = parse_instruction(
+            &token_metadata::TokenMetadataParser,
+            mpl_token_metadata::ID,
+            &instruction,
+        )
+        .unwrap();
+        let value = serde_json::to_value(result.result_type()).unwrap();
+        assert_eq!(
+            serialized_account_count(&value["TokenMetadataInstruction"]["data"]),
+            instruction.accounts.len()
+        );
+    }
+
+    let instruction = UpdateCollectionV1 {
+        collection: keys[0],
+        payer: keys[1],
+        authority: Some(keys[2]),
+        new_update_authority: Some(keys[3]),
+        system_program: keys[4],
+        log_wrapper: Some(keys[5]),
+    }
+    .instruction(UpdateCollectionV1InstructionArgs {
+        new_name: None,
+        new_uri: None,
+    });
+    let result =
+        parse_instruction(&mpl_core_program::MplCoreParser, mpl_core::ID, &instruction).unwrap();
+    let value = serde_json::to_value(result.result_type()).unwrap();
+    assert_eq!(
+        serialized_account_count(&value["MplCoreInstruction"]["data"]),
+        instruction.accounts.len()
+    );
+}
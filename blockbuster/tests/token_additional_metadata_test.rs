@@ -0,0 +1,83 @@
+#[cfg(test)]
+use blockbuster::{
+    error::BlockbusterError,
+    programs::token_extensions::extension::{
+        AdditionalMetadata, ShadowMetadata, MAX_ADDITIONAL_METADATA_LEN,
+    },
+};
+use borsh::BorshSerialize;
+use helpers::*;
+use spl_token_metadata_interface::state::TokenMetadata;
+
+mod helpers;
+
+fn pairs(entries: &[(&str, &str)]) -> Vec<(String, String)> {
+    entries
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_from_token_metadata() {
+    let token_metadata = TokenMetadata {
+        mint: random_pubkey(),
+        name: "name".to_string(),
+        additional_metadata: pairs(&[("eyes", "blue"), ("hat", "none"), ("eyes", "green")]),
+        ..Default::default()
+    };
+    let additional_metadata = ShadowMetadata::from(token_metadata.clone()).additional_metadata;
+
+    assert_eq!(additional_metadata.len(), 3);
+    assert_eq!(
+        additional_metadata.iter().collect::<Vec<_>>(),
+        vec![("eyes", "blue"), ("hat", "none"), ("eyes", "green")]
+    );
+    assert_eq!(additional_metadata.get("eyes"), Some("blue"));
+    assert_eq!(
+        additional_metadata.get_all("eyes").collect::<Vec<_>>(),
+        vec!["blue", "green"]
+    );
+    assert!(additional_metadata.contains_key("hat"));
+    assert_eq!(additional_metadata.get("mouth"), None);
+    assert_eq!(
+        additional_metadata.serialized_len(),
+        token_metadata
+            .additional_metadata
+            .try_to_vec()
+            .unwrap()
+            .len()
+    );
+}
+
+#[test]
+fn test_serde_round_trip() {
+    let additional_metadata =
+        AdditionalMetadata::new(pairs(&[("b", "2"), ("a", "1"), ("b", "3")])).unwrap();
+
+    let json = serde_json::to_string(&additional_metadata).unwrap();
+    assert_eq!(json, r#"[["b","2"],["a","1"],["b","3"]]"#);
+    assert_eq!(
+        serde_json::from_str::<AdditionalMetadata>(&json).unwrap(),
+        additional_metadata
+    );
+}
+
+#[test]
+fn test_max_length() {
+    // The u32 count plus one entry with two u32 lengths.
+    let value_len = MAX_ADDITIONAL_METADATA_LEN - 4 - 8 - 1;
+    let entries = vec![("k".to_string(), "v".repeat(value_len))];
+    assert!(AdditionalMetadata::new(entries).is_ok());
+
+    let entries = vec![("k".to_string(), "v".repeat(value_len + 1))];
+    assert!(matches!(
+        AdditionalMetadata::new(entries.clone()),
+        Err(BlockbusterError::AdditionalMetadataTooLong(len, MAX_ADDITIONAL_METADATA_LEN))
+            if len == MAX_ADDITIONAL_METADATA_LEN + 1
+    ));
+    assert!(
+        serde_json::from_str::<AdditionalMetadata>(&serde_json::to_string(&entries).unwrap())
+            .is_err()
+    );
+}
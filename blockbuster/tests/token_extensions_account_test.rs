@@ -0,0 +1,190 @@
+#[cfg(test)]
+use blockbuster::{
+    program_handler::ProgramParser,
+    programs::{
+        token_extensions::{
+            extension::UnknownExtension, Token2022AccountParser, TokenExtensionsProgramAccount,
+        },
+        ProgramParseResult,
+    },
+};
+use helpers::*;
+use solana_sdk::{program_pack::Pack, pubkey::Pubkey};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_2022::{
+    extension::{AccountType, ExtensionType},
+    state::{Account, AccountState, Mint},
+};
+
+mod helpers;
+
+// Lays out base state, padding, account type and TLV entries the way Token-2022 stores them.
+fn account_with_extensions<S: Pack>(
+    base: S,
+    account_type: AccountType,
+    entries: &[(u16, Vec<u8>)],
+) -> Vec<u8> {
+    let mut data = vec![0; Account::LEN];
+    S::pack(base, &mut data[..S::LEN]).unwrap();
+    data.push(account_type as u8);
+    for (extension_type, value) in entries {
+        data.extend_from_slice(&extension_type.to_le_bytes());
+        data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        data.extend_from_slice(value);
+    }
+    data
+}
+
+fn scaled_ui_amount(authority: &Pubkey, multiplier: f64) -> Vec<u8> {
+    let mut value = authority.to_bytes().to_vec();
+    value.extend_from_slice(&multiplier.to_le_bytes());
+    value.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+    value.extend_from_slice(&(multiplier * 2.0).to_le_bytes());
+    value
+}
+
+#[test]
+fn test_mint_extensions() {
+    let authority_key = random_pubkey();
+    let authority = OptionalNonZeroPubkey::try_from(Some(authority_key)).unwrap();
+    let metadata_address = random_pubkey();
+    let mint = Mint {
+        supply: 1000,
+        decimals: 6,
+        is_initialized: true,
+        ..Default::default()
+    };
+    let data = account_with_extensions(
+        mint,
+        AccountType::Mint,
+        &[
+            // An extension type this crate does not know, ahead of known ones.
+            (200, vec![1, 2, 3]),
+            (25, scaled_ui_amount(&authority_key, 1.5)),
+            (
+                ExtensionType::MetadataPointer.into(),
+                [authority_key.to_bytes(), metadata_address.to_bytes()].concat(),
+            ),
+            (26, [authority_key.to_bytes().as_slice(), &[1]].concat()),
+            (ExtensionType::NonTransferable.into(), vec![]),
+        ],
+    );
+
+    let result = Token2022AccountParser.handle_account(&data).unwrap();
+    if let ProgramParseResult::TokenExtensionsProgramAccount(
+        TokenExtensionsProgramAccount::MintAccount(parsed),
+    ) = result.result_type()
+    {
+        assert_eq!(parsed.account, mint);
+        let extensions = &parsed.extensions;
+        assert!(extensions.is_some());
+
+        let scaled_ui_amount = extensions.scaled_ui_amount.unwrap();
+        assert_eq!(scaled_ui_amount.authority, authority);
+        assert_eq!(scaled_ui_amount.multiplier, 1.5);
+        assert_eq!(
+            i64::from(scaled_ui_amount.new_multiplier_effective_timestamp),
+            1_700_000_000
+        );
+        assert_eq!(scaled_ui_amount.new_multiplier, 3.0);
+
+        let pausable = extensions.pausable.unwrap();
+        assert_eq!(pausable.authority, authority);
+        assert!(bool::from(pausable.paused));
+
+        assert_eq!(
+            Option::<Pubkey>::from(extensions.metadata_pointer.unwrap().metadata_address),
+            Some(metadata_address)
+        );
+        assert_eq!(extensions.non_transferable, Some(true));
+        assert_eq!(
+            extensions.unknown_extensions,
+            vec![UnknownExtension {
+                extension_type: 200,
+                data: vec![1, 2, 3],
+            }]
+        );
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
+
+#[test]
+fn test_token_account_extensions() {
+    let account = Account {
+        mint: random_pubkey(),
+        owner: random_pubkey(),
+        amount: 10,
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+    let data = account_with_extensions(
+        account,
+        AccountType::Account,
+        &[
+            (ExtensionType::ImmutableOwner.into(), vec![]),
+            (
+                ExtensionType::ConfidentialTransferFeeAmount.into(),
+                vec![7; 64],
+            ),
+            (ExtensionType::TransferHookAccount.into(), vec![1]),
+            (27, vec![]),
+            // Wrong size for a transfer fee amount, so it is passed through undecoded.
+            (ExtensionType::TransferFeeAmount.into(), vec![1, 2]),
+        ],
+    );
+
+    let result = Token2022AccountParser.handle_account(&data).unwrap();
+    if let ProgramParseResult::TokenExtensionsProgramAccount(
+        TokenExtensionsProgramAccount::TokenAccount(parsed),
+    ) = result.result_type()
+    {
+        assert_eq!(parsed.account, account);
+        let extensions = &parsed.extensions;
+        assert_eq!(extensions.immutable_owner, Some(true));
+        assert_eq!(
+            extensions
+                .confidential_transfer_fee_amount
+                .as_ref()
+                .unwrap()
+                .withheld_amount,
+            bs58::encode([7; 64]).into_string()
+        );
+        assert!(bool::from(
+            extensions.transfer_hook_account.unwrap().transferring
+        ));
+        assert_eq!(extensions.pausable_account, Some(true));
+        assert_eq!(extensions.transfer_fee_amount, None);
+        assert_eq!(
+            extensions.unknown_extensions,
+            vec![UnknownExtension {
+                extension_type: ExtensionType::TransferFeeAmount.into(),
+                data: vec![1, 2],
+            }]
+        );
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
+
+#[test]
+fn test_token_account_with_only_pausable_account() {
+    let account = Account {
+        mint: random_pubkey(),
+        owner: random_pubkey(),
+        state: AccountState::Initialized,
+        ..Default::default()
+    };
+    let data = account_with_extensions(account, AccountType::Account, &[(27, vec![])]);
+
+    let result = Token2022AccountParser.handle_account(&data).unwrap();
+    if let ProgramParseResult::TokenExtensionsProgramAccount(
+        TokenExtensionsProgramAccount::TokenAccount(parsed),
+    ) = result.result_type()
+    {
+        assert_eq!(parsed.extensions.pausable_account, Some(true));
+        assert!(parsed.extensions.is_some());
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
@@ -0,0 +1,289 @@
+#[cfg(test)]
+use blockbuster::{
+    program_handler::{ParseResult, ProgramParser},
+    programs::{
+        token_account::instruction::TokenInstructionAccounts,
+        token_extensions::{
+            instruction::{
+                TokenExtensionsInstruction, TokenGroupInstructionAccounts,
+                TokenMetadataInstructionAccounts,
+            },
+            Token2022AccountParser,
+        },
+        ProgramParseResult,
+    },
+};
+use helpers::*;
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+use spl_token_2022::{
+    extension::{
+        metadata_pointer::{self, instruction::MetadataPointerInstruction},
+        transfer_fee::{self, instruction::TransferFeeInstruction},
+    },
+    instruction::{transfer_checked, TokenInstruction},
+};
+use spl_token_group_interface::instruction::{initialize_member, TokenGroupInstruction};
+use spl_token_metadata_interface::instruction::{initialize, TokenMetadataInstruction};
+
+mod helpers;
+
+fn token_extensions_instruction(result: &dyn ParseResult) -> &TokenExtensionsInstruction {
+    match result.result_type() {
+        ProgramParseResult::TokenExtensionsInstruction(ix) => ix,
+        _ => panic!("Unexpected ProgramParseResult variant"),
+    }
+}
+
+#[test]
+fn test_setup() {
+    let subject = Token2022AccountParser;
+    assert_eq!(subject.key(), spl_token_2022::id());
+    assert!(subject.handles_instructions());
+    assert!(subject.handles_account_updates());
+}
+
+#[test]
+fn test_transfer_checked() {
+    let keys = random_list_of(4, |_| random_pubkey());
+    let instruction = transfer_checked(
+        &spl_token_2022::id(),
+        &keys[0],
+        &keys[1],
+        &keys[2],
+        &keys[3],
+        &[],
+        100,
+        6,
+    )
+    .unwrap();
+
+    let result =
+        parse_instruction(&Token2022AccountParser, spl_token_2022::id(), &instruction).unwrap();
+    match token_extensions_instruction(result.as_ref()) {
+        TokenExtensionsInstruction::Token(ix) => {
+            assert_eq!(
+                ix.instruction(),
+                TokenInstruction::TransferChecked {
+                    amount: 100,
+                    decimals: 6
+                }
+            );
+            assert_eq!(
+                ix.accounts,
+                TokenInstructionAccounts {
+                    source: Some(keys[0]),
+                    mint: Some(keys[1]),
+                    destination: Some(keys[2]),
+                    authority: Some(keys[3]),
+                    ..Default::default()
+                }
+            );
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_withdraw_withheld_tokens_from_accounts() {
+    let keys = random_list_of(7, |_| random_pubkey());
+    let instruction = transfer_fee::instruction::withdraw_withheld_tokens_from_accounts(
+        &spl_token_2022::id(),
+        &keys[0],
+        &keys[1],
+        &keys[2],
+        &[&keys[3], &keys[4]],
+        &[&keys[5], &keys[6]],
+    )
+    .unwrap();
+
+    let result =
+        parse_instruction(&Token2022AccountParser, spl_token_2022::id(), &instruction).unwrap();
+    match token_extensions_instruction(result.as_ref()) {
+        TokenExtensionsInstruction::TransferFee {
+            instruction,
+            accounts,
+        } => {
+            assert_eq!(
+                *instruction,
+                TransferFeeInstruction::WithdrawWithheldTokensFromAccounts {
+                    num_token_accounts: 2
+                }
+            );
+            assert_eq!(
+                *accounts,
+                TokenInstructionAccounts {
+                    mint: Some(keys[0]),
+                    destination: Some(keys[1]),
+                    authority: Some(keys[2]),
+                    signers: vec![keys[3], keys[4]],
+                    token_accounts: vec![keys[5], keys[6]],
+                    ..Default::default()
+                }
+            );
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_harvest_withheld_tokens_to_mint() {
+    let keys = random_list_of(3, |_| random_pubkey());
+    let instruction = transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+        &spl_token_2022::id(),
+        &keys[0],
+        &[&keys[1], &keys[2]],
+    )
+    .unwrap();
+
+    let result =
+        parse_instruction(&Token2022AccountParser, spl_token_2022::id(), &instruction).unwrap();
+    match token_extensions_instruction(result.as_ref()) {
+        TokenExtensionsInstruction::TransferFee { accounts, .. } => {
+            assert_eq!(
+                *accounts,
+                TokenInstructionAccounts {
+                    mint: Some(keys[0]),
+                    token_accounts: vec![keys[1], keys[2]],
+                    ..Default::default()
+                }
+            );
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_metadata_pointer() {
+    let keys = random_list_of(3, |_| random_pubkey());
+    let instruction = metadata_pointer::instruction::initialize(
+        &spl_token_2022::id(),
+        &keys[0],
+        Some(keys[1]),
+        Some(keys[2]),
+    )
+    .unwrap();
+
+    let result =
+        parse_instruction(&Token2022AccountParser, spl_token_2022::id(), &instruction).unwrap();
+    match token_extensions_instruction(result.as_ref()) {
+        TokenExtensionsInstruction::MetadataPointer {
+            instruction,
+            authority,
+            pointer,
+            accounts,
+        } => {
+            assert_eq!(*instruction, MetadataPointerInstruction::Initialize);
+            assert_eq!(
+                *authority,
+                Some(OptionalNonZeroPubkey::try_from(Some(keys[1])).unwrap())
+            );
+            assert_eq!(
+                Option::<Pubkey>::from(pointer.metadata_address),
+                Some(keys[2])
+            );
+            assert_eq!(accounts.mint, Some(keys[0]));
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_token_metadata_initialize() {
+    let keys = random_list_of(4, |_| random_pubkey());
+    let instruction = initialize(
+        &spl_token_2022::id(),
+        &keys[0],
+        &keys[1],
+        &keys[2],
+        &keys[3],
+        "name".to_string(),
+        "SYM".to_string(),
+        "https://example.com".to_string(),
+    );
+
+    let result =
+        parse_instruction(&Token2022AccountParser, spl_token_2022::id(), &instruction).unwrap();
+    match token_extensions_instruction(result.as_ref()) {
+        TokenExtensionsInstruction::TokenMetadata {
+            instruction,
+            accounts,
+        } => {
+            match instruction {
+                TokenMetadataInstruction::Initialize(data) => {
+                    assert_eq!(data.name, "name");
+                    assert_eq!(data.symbol, "SYM");
+                    assert_eq!(data.uri, "https://example.com");
+                }
+                _ => panic!("Unexpected token metadata instruction"),
+            }
+            assert_eq!(
+                *accounts,
+                TokenMetadataInstructionAccounts {
+                    metadata: keys[0],
+                    update_authority: Some(keys[1]),
+                    mint: Some(keys[2]),
+                    mint_authority: Some(keys[3]),
+                }
+            );
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_token_group_initialize_member() {
+    let keys = random_list_of(5, |_| random_pubkey());
+    let instruction = initialize_member(
+        &spl_token_2022::id(),
+        &keys[0],
+        &keys[1],
+        &keys[2],
+        &keys[3],
+        &keys[4],
+    );
+
+    let result =
+        parse_instruction(&Token2022AccountParser, spl_token_2022::id(), &instruction).unwrap();
+    match token_extensions_instruction(result.as_ref()) {
+        TokenExtensionsInstruction::TokenGroup {
+            instruction,
+            accounts,
+        } => {
+            assert!(matches!(
+                instruction,
+                TokenGroupInstruction::InitializeMember(_)
+            ));
+            assert_eq!(
+                *accounts,
+                TokenGroupInstructionAccounts {
+                    group: keys[3],
+                    update_authority: Some(keys[4]),
+                    mint: Some(keys[1]),
+                    mint_authority: Some(keys[2]),
+                    member: Some(keys[0]),
+                }
+            );
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_invalid_instructions() {
+    let instruction = Instruction::new_with_bytes(spl_token_2022::id(), &[], vec![]);
+    assert!(
+        parse_instruction(&Token2022AccountParser, spl_token_2022::id(), &instruction).is_err()
+    );
+
+    let instruction = Instruction::new_with_bytes(spl_token_2022::id(), &[255, 1, 2], vec![]);
+    assert!(
+        parse_instruction(&Token2022AccountParser, spl_token_2022::id(), &instruction).is_err()
+    );
+
+    // Metadata pointer extension with an unknown sub-instruction.
+    let instruction = Instruction::new_with_bytes(spl_token_2022::id(), &[39, 9], vec![]);
+    assert!(
+        parse_instruction(&Token2022AccountParser, spl_token_2022::id(), &instruction).is_err()
+    );
+}
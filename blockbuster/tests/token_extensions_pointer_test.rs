@@ -0,0 +1,186 @@
+#[cfg(test)]
+use blockbuster::{
+    parser_registry::ParserRegistry,
+    programs::{
+        token_account::TokenProgramAccount,
+        token_extensions::{
+            extension::{ShadowGroupPointer, ShadowMetadata, ShadowMetadataPointer},
+            pointer::ResolvedPointer,
+            MintAccount, MintAccountExtensions,
+        },
+        token_metadata::TokenMetadataAccountData,
+        ProgramParseResult,
+    },
+};
+use borsh::BorshSerialize;
+use helpers::*;
+use mpl_token_metadata::{
+    accounts::Metadata,
+    types::{Key, TokenStandard},
+};
+use solana_sdk::{account::Account, program_pack::Pack, pubkey::Pubkey};
+use spl_pod::optional_keys::OptionalNonZeroPubkey;
+
+mod helpers;
+
+fn mint_with_pointers(metadata_address: Pubkey, group_address: Pubkey) -> MintAccount {
+    MintAccount {
+        account: spl_token_2022::state::Mint {
+            is_initialized: true,
+            ..Default::default()
+        },
+        extensions: MintAccountExtensions {
+            metadata_pointer: Some(ShadowMetadataPointer {
+                metadata_address: OptionalNonZeroPubkey::try_from(Some(metadata_address)).unwrap(),
+            }),
+            group_pointer: Some(ShadowGroupPointer {
+                group_address: OptionalNonZeroPubkey::try_from(Some(group_address)).unwrap(),
+            }),
+            metadata: Some(ShadowMetadata {
+                name: "name".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    }
+}
+
+fn account(owner: Pubkey, data: Vec<u8>) -> Account {
+    Account {
+        owner,
+        data,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_pointer_to_mint() {
+    let registry = ParserRegistry::with_default_parsers();
+    let mint_address = random_pubkey();
+    let mint = mint_with_pointers(mint_address, mint_address);
+
+    let resolved = mint
+        .resolve_metadata(&mint_address, &registry, |_| panic!("no lookup expected"))
+        .unwrap();
+    match resolved {
+        Some(ResolvedPointer::Mint(metadata)) => assert_eq!(metadata.name, "name"),
+        _ => panic!("Unexpected resolved pointer"),
+    }
+
+    // The mint has no group extension.
+    assert!(mint
+        .resolve_group(&mint_address, &registry, |_| None)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn test_pointer_to_token_metadata() {
+    let registry = ParserRegistry::with_default_parsers();
+    let mint_address = random_pubkey();
+    let metadata_address = Metadata::find_pda(&mint_address).0;
+    let mint = mint_with_pointers(metadata_address, random_pubkey());
+
+    let metadata = Metadata {
+        key: Key::MetadataV1,
+        update_authority: random_pubkey(),
+        mint: mint_address,
+        name: "name".to_string(),
+        symbol: "SYM".to_string(),
+        uri: "https://example.com".to_string(),
+        seller_fee_basis_points: 0,
+        creators: None,
+        primary_sale_happened: false,
+        is_mutable: true,
+        edition_nonce: None,
+        token_standard: Some(TokenStandard::Fungible),
+        collection: None,
+        uses: None,
+        collection_details: None,
+        programmable_config: None,
+    };
+
+    let resolved = mint
+        .resolve_metadata(&mint_address, &registry, |address| {
+            assert_eq!(address, &metadata_address);
+            Some(account(
+                mpl_token_metadata::ID,
+                metadata.try_to_vec().unwrap(),
+            ))
+        })
+        .unwrap();
+    match resolved {
+        Some(ResolvedPointer::Account {
+            address,
+            owner,
+            parsed: Some(parsed),
+        }) => {
+            assert_eq!(address, metadata_address);
+            assert_eq!(owner, mpl_token_metadata::ID);
+            match parsed.result_type() {
+                ProgramParseResult::TokenMetadata(state) => match &state.data {
+                    TokenMetadataAccountData::MetadataV1(parsed) => {
+                        assert_eq!(parsed, &metadata)
+                    }
+                    _ => panic!("Unexpected TokenMetadataAccountData variant"),
+                },
+                _ => panic!("Unexpected ProgramParseResult variant"),
+            }
+        }
+        _ => panic!("Unexpected resolved pointer"),
+    }
+}
+
+#[test]
+fn test_pointer_to_other_account() {
+    let registry = ParserRegistry::with_default_parsers();
+    let mint_address = random_pubkey();
+    let group_address = random_pubkey();
+    let mint = mint_with_pointers(random_pubkey(), group_address);
+
+    // The lookup found nothing.
+    assert!(mint
+        .resolve_metadata(&mint_address, &registry, |_| None)
+        .unwrap()
+        .is_none());
+
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(
+        spl_token::state::Mint {
+            is_initialized: true,
+            ..Default::default()
+        },
+        &mut data,
+    )
+    .unwrap();
+    let resolved = mint
+        .resolve_group(&mint_address, &registry, |_| {
+            Some(account(spl_token::id(), data))
+        })
+        .unwrap();
+    match resolved {
+        Some(ResolvedPointer::Account {
+            address,
+            parsed: Some(parsed),
+            ..
+        }) => {
+            assert_eq!(address, group_address);
+            assert!(matches!(
+                parsed.result_type(),
+                ProgramParseResult::TokenProgramAccount(TokenProgramAccount::Mint(_))
+            ));
+        }
+        _ => panic!("Unexpected resolved pointer"),
+    }
+
+    // Accounts owned by programs without a registered parser are not decoded.
+    let resolved = mint
+        .resolve_group(&mint_address, &registry, |_| {
+            Some(account(random_pubkey(), vec![1, 2, 3]))
+        })
+        .unwrap();
+    assert!(matches!(
+        resolved,
+        Some(ResolvedPointer::Account { parsed: None, .. })
+    ));
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+use blockbuster::programs::{
+    token_account::{instruction::TokenInstructionAccounts, TokenAccountParser},
+    ProgramParseResult,
+};
+use helpers::*;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use spl_token::instruction::{
+    close_account, set_authority, transfer, transfer_checked, ui_amount_to_amount, AuthorityType,
+    TokenInstruction,
+};
+
+mod helpers;
+
+fn check(instruction: Instruction, expected: TokenInstruction, accounts: TokenInstructionAccounts) {
+    let result = parse_instruction(&TokenAccountParser, spl_token::id(), &instruction).unwrap();
+    if let ProgramParseResult::TokenProgramInstruction(ix) = result.result_type() {
+        assert_eq!(ix.instruction(), expected);
+        assert_eq!(ix.accounts, accounts);
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
+
+#[test]
+fn test_transfer() {
+    let keys = random_list_of(3, |_| random_pubkey());
+    check(
+        transfer(&spl_token::id(), &keys[0], &keys[1], &keys[2], &[], 5).unwrap(),
+        TokenInstruction::Transfer { amount: 5 },
+        TokenInstructionAccounts {
+            source: Some(keys[0]),
+            destination: Some(keys[1]),
+            authority: Some(keys[2]),
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn test_transfer_checked_multisig() {
+    let keys = random_list_of(6, |_| random_pubkey());
+    check(
+        transfer_checked(
+            &spl_token::id(),
+            &keys[0],
+            &keys[1],
+            &keys[2],
+            &keys[3],
+            &[&keys[4], &keys[5]],
+            1,
+            0,
+        )
+        .unwrap(),
+        TokenInstruction::TransferChecked {
+            amount: 1,
+            decimals: 0,
+        },
+        TokenInstructionAccounts {
+            source: Some(keys[0]),
+            mint: Some(keys[1]),
+            destination: Some(keys[2]),
+            authority: Some(keys[3]),
+            signers: vec![keys[4], keys[5]],
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn test_set_authority_and_close() {
+    let keys = random_list_of(3, |_| random_pubkey());
+    check(
+        set_authority(
+            &spl_token::id(),
+            &keys[0],
+            Some(&keys[1]),
+            AuthorityType::AccountOwner,
+            &keys[2],
+            &[],
+        )
+        .unwrap(),
+        TokenInstruction::SetAuthority {
+            authority_type: AuthorityType::AccountOwner,
+            new_authority: Some(keys[1]).into(),
+        },
+        TokenInstructionAccounts {
+            account: Some(keys[0]),
+            authority: Some(keys[2]),
+            ..Default::default()
+        },
+    );
+    check(
+        close_account(&spl_token::id(), &keys[0], &keys[1], &keys[2], &[]).unwrap(),
+        TokenInstruction::CloseAccount,
+        TokenInstructionAccounts {
+            account: Some(keys[0]),
+            destination: Some(keys[1]),
+            authority: Some(keys[2]),
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn test_ui_amount_to_amount() {
+    let mint = random_pubkey();
+    check(
+        ui_amount_to_amount(&spl_token::id(), &mint, "1.5").unwrap(),
+        TokenInstruction::UiAmountToAmount { ui_amount: "1.5" },
+        TokenInstructionAccounts {
+            mint: Some(mint),
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn test_invalid_instructions() {
+    let accounts: Vec<AccountMeta> =
+        random_list_of(2, |_| AccountMeta::new(random_pubkey(), false));
+    for data in [vec![], vec![255], vec![3, 1]] {
+        let instruction = Instruction::new_with_bytes(spl_token::id(), &data, accounts.clone());
+        assert!(parse_instruction(&TokenAccountParser, spl_token::id(), &instruction).is_err());
+    }
+
+    // Transfer with too few accounts.
+    let instruction = Instruction::new_with_bytes(
+        spl_token::id(),
+        &TokenInstruction::Transfer { amount: 1 }.pack(),
+        accounts,
+    );
+    assert!(parse_instruction(&TokenAccountParser, spl_token::id(), &instruction).is_err());
+}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+use blockbuster::{
+    program_handler::{ParseResult, ProgramParser},
+    programs::{
+        token_metadata::{TokenMetadataAccountData, TokenMetadataParser},
+        ProgramParseResult,
+    },
+};
+use borsh::BorshSerialize;
+use helpers::*;
+use mpl_token_metadata::{
+    accounts::{
+        EditionMarkerV2, HolderDelegateRecord, MetadataDelegateRecord, TokenOwnedEscrow,
+        TokenRecord,
+    },
+    types::{EscrowAuthority, Key, TokenDelegateRole, TokenState},
+};
+
+mod helpers;
+
+fn account_data(result: &dyn ParseResult) -> &TokenMetadataAccountData {
+    match result.result_type() {
+        ProgramParseResult::TokenMetadata(state) => &state.data,
+        _ => panic!("Unexpected ProgramParseResult variant"),
+    }
+}
+
+#[test]
+fn test_token_record() {
+    let record = TokenRecord {
+        key: Key::TokenRecord,
+        bump: 255,
+        state: TokenState::Locked,
+        rule_set_revision: Some(3),
+        delegate: Some(random_pubkey()),
+        delegate_role: Some(TokenDelegateRole::Staking),
+        locked_transfer: None,
+    };
+    // Token records are allocated at their maximum size.
+    let mut data = record.try_to_vec().unwrap();
+    data.resize(80, 0);
+
+    let result = TokenMetadataParser.handle_account(&data).unwrap();
+    match account_data(result.as_ref()) {
+        TokenMetadataAccountData::TokenRecord(parsed) => assert_eq!(parsed, &record),
+        _ => panic!("Unexpected TokenMetadataAccountData variant"),
+    }
+}
+
+#[test]
+fn test_delegate_records() {
+    let record = MetadataDelegateRecord {
+        key: Key::MetadataDelegate,
+        bump: 254,
+        mint: random_pubkey(),
+        delegate: random_pubkey(),
+        update_authority: random_pubkey(),
+    };
+    let result = TokenMetadataParser
+        .handle_account(&record.try_to_vec().unwrap())
+        .unwrap();
+    match account_data(result.as_ref()) {
+        TokenMetadataAccountData::MetadataDelegateRecord(parsed) => assert_eq!(parsed, &record),
+        _ => panic!("Unexpected TokenMetadataAccountData variant"),
+    }
+
+    let record = HolderDelegateRecord {
+        key: Key::HolderDelegate,
+        bump: 253,
+        mint: random_pubkey(),
+        delegate: random_pubkey(),
+        update_authority: random_pubkey(),
+    };
+    let result = TokenMetadataParser
+        .handle_account(&record.try_to_vec().unwrap())
+        .unwrap();
+    match account_data(result.as_ref()) {
+        TokenMetadataAccountData::HolderDelegateRecord(parsed) => assert_eq!(parsed, &record),
+        _ => panic!("Unexpected TokenMetadataAccountData variant"),
+    }
+}
+
+#[test]
+fn test_edition_marker_v2_and_escrow() {
+    let marker = EditionMarkerV2 {
+        key: Key::EditionMarkerV2,
+        ledger: vec![0b1010_0000, 0b0000_0001],
+    };
+    let result = TokenMetadataParser
+        .handle_account(&marker.try_to_vec().unwrap())
+        .unwrap();
+    match account_data(result.as_ref()) {
+        TokenMetadataAccountData::EditionMarkerV2(parsed) => assert_eq!(parsed, &marker),
+        _ => panic!("Unexpected TokenMetadataAccountData variant"),
+    }
+
+    let escrow = TokenOwnedEscrow {
+        key: Key::TokenOwnedEscrow,
+        base_token: random_pubkey(),
+        authority: EscrowAuthority::Creator(random_pubkey()),
+        bump: 252,
+    };
+    let result = TokenMetadataParser
+        .handle_account(&escrow.try_to_vec().unwrap())
+        .unwrap();
+    match account_data(result.as_ref()) {
+        TokenMetadataAccountData::TokenOwnedEscrow(parsed) => assert_eq!(parsed, &escrow),
+        _ => panic!("Unexpected TokenMetadataAccountData variant"),
+    }
+}
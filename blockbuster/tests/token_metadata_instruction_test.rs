@@ -0,0 +1,270 @@
+#[cfg(test)]
+use blockbuster::{
+    instruction::InstructionBundle,
+    program_handler::{ParseResult, ProgramParser},
+    programs::{
+        token_metadata::{instruction::TokenMetadataInstructionData, TokenMetadataParser},
+        ProgramParseResult,
+    },
+};
+use helpers::*;
+use mpl_token_metadata::{
+    instructions::{
+        BurnNft, SetAndVerifySizedCollectionItem, SignMetadata, Transfer, TransferInstructionArgs,
+        Unverify, UnverifyCollection, UnverifyInstructionArgs, Verify, VerifyInstructionArgs,
+    },
+    types::{TransferArgs, VerificationArgs},
+};
+use solana_sdk::instruction::Instruction;
+
+mod helpers;
+
+fn instruction_data(result: &dyn ParseResult) -> &TokenMetadataInstructionData {
+    match result.result_type() {
+        ProgramParseResult::TokenMetadataInstruction(ix) => &ix.data,
+        _ => panic!("Unexpected ProgramParseResult variant"),
+    }
+}
+
+#[test]
+fn test_setup() {
+    let subject = TokenMetadataParser;
+    assert_eq!(subject.key(), mpl_token_metadata::ID);
+    assert!(subject.handles_instructions());
+    assert!(subject.handles_account_updates());
+}
+
+#[test]
+fn test_verify_collection() {
+    let keys = random_list_of(6, |_| random_pubkey());
+    let instruction = Verify {
+        authority: keys[0],
+        delegate_record: None,
+        metadata: keys[1],
+        collection_mint: Some(keys[2]),
+        collection_metadata: Some(keys[3]),
+        collection_master_edition: Some(keys[4]),
+        system_program: keys[5],
+        sysvar_instructions: solana_sdk::sysvar::instructions::id(),
+    }
+    .instruction(VerifyInstructionArgs {
+        verification_args: VerificationArgs::CollectionV1,
+    });
+
+    let result =
+        parse_instruction(&TokenMetadataParser, mpl_token_metadata::ID, &instruction).unwrap();
+    match instruction_data(result.as_ref()) {
+        TokenMetadataInstructionData::Verify { accounts, args } => {
+            assert_eq!(args, &VerificationArgs::CollectionV1);
+            assert_eq!(accounts.authority, keys[0]);
+            assert_eq!(accounts.delegate_record, None);
+            assert_eq!(accounts.metadata, keys[1]);
+            assert_eq!(accounts.collection_mint, Some(keys[2]));
+            assert_eq!(accounts.collection_metadata, Some(keys[3]));
+            assert_eq!(accounts.collection_master_edition, Some(keys[4]));
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_unverify_creator() {
+    let keys = random_list_of(3, |_| random_pubkey());
+    let instruction = Unverify {
+        authority: keys[0],
+        delegate_record: None,
+        metadata: keys[1],
+        collection_mint: None,
+        collection_metadata: None,
+        system_program: keys[2],
+        sysvar_instructions: solana_sdk::sysvar::instructions::id(),
+    }
+    .instruction(UnverifyInstructionArgs {
+        verification_args: VerificationArgs::CreatorV1,
+    });
+
+    let result =
+        parse_instruction(&TokenMetadataParser, mpl_token_metadata::ID, &instruction).unwrap();
+    match instruction_data(result.as_ref()) {
+        TokenMetadataInstructionData::Unverify { accounts, args } => {
+            assert_eq!(args, &VerificationArgs::CreatorV1);
+            assert_eq!(accounts.authority, keys[0]);
+            assert_eq!(accounts.metadata, keys[1]);
+            assert_eq!(accounts.collection_mint, None);
+            assert_eq!(accounts.collection_metadata, None);
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_transfer() {
+    let keys = random_list_of(12, |_| random_pubkey());
+    let instruction = Transfer {
+        token: keys[0],
+        token_owner: keys[1],
+        destination_token: keys[2],
+        destination_owner: keys[3],
+        mint: keys[4],
+        metadata: keys[5],
+        edition: Some(keys[6]),
+        token_record: Some(keys[7]),
+        destination_token_record: Some(keys[8]),
+        authority: keys[9],
+        payer: keys[10],
+        system_program: keys[11],
+        sysvar_instructions: solana_sdk::sysvar::instructions::id(),
+        spl_token_program: spl_token::id(),
+        spl_ata_program: random_pubkey(),
+        authorization_rules_program: None,
+        authorization_rules: None,
+    }
+    .instruction(TransferInstructionArgs {
+        transfer_args: TransferArgs::V1 {
+            amount: 1,
+            authorization_data: None,
+        },
+    });
+
+    let result =
+        parse_instruction(&TokenMetadataParser, mpl_token_metadata::ID, &instruction).unwrap();
+    match instruction_data(result.as_ref()) {
+        TokenMetadataInstructionData::Transfer { accounts, args } => {
+            assert_eq!(
+                args,
+                &TransferArgs::V1 {
+                    amount: 1,
+                    authorization_data: None
+                }
+            );
+            assert_eq!(accounts.token_owner, keys[1]);
+            assert_eq!(accounts.destination_owner, keys[3]);
+            assert_eq!(accounts.mint, keys[4]);
+            assert_eq!(accounts.token_record, Some(keys[7]));
+            assert_eq!(accounts.destination_token_record, Some(keys[8]));
+            assert_eq!(accounts.authorization_rules_program, None);
+            assert_eq!(accounts.authorization_rules, None);
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_legacy_collection_instructions() {
+    let keys = random_list_of(8, |_| random_pubkey());
+    let instruction = SetAndVerifySizedCollectionItem {
+        metadata: keys[0],
+        collection_authority: keys[1],
+        payer: keys[2],
+        update_authority: keys[3],
+        collection_mint: keys[4],
+        collection: keys[5],
+        collection_master_edition_account: keys[6],
+        collection_authority_record: Some(keys[7]),
+    }
+    .instruction();
+
+    let result =
+        parse_instruction(&TokenMetadataParser, mpl_token_metadata::ID, &instruction).unwrap();
+    match instruction_data(result.as_ref()) {
+        TokenMetadataInstructionData::SetAndVerifySizedCollectionItem { accounts } => {
+            assert_eq!(accounts.metadata, keys[0]);
+            assert_eq!(accounts.collection_authority, keys[1]);
+            assert_eq!(accounts.update_authority, keys[3]);
+            assert_eq!(accounts.collection_mint, keys[4]);
+            assert_eq!(accounts.collection, keys[5]);
+            assert_eq!(accounts.collection_authority_record, Some(keys[7]));
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+
+    let instruction = UnverifyCollection {
+        metadata: keys[0],
+        collection_authority: keys[1],
+        collection_mint: keys[2],
+        collection: keys[3],
+        collection_master_edition_account: keys[4],
+        collection_authority_record: None,
+    }
+    .instruction();
+
+    let result =
+        parse_instruction(&TokenMetadataParser, mpl_token_metadata::ID, &instruction).unwrap();
+    match instruction_data(result.as_ref()) {
+        TokenMetadataInstructionData::UnverifyCollection { accounts } => {
+            assert_eq!(accounts.metadata, keys[0]);
+            assert_eq!(accounts.collection_mint, keys[2]);
+            assert_eq!(accounts.collection, keys[3]);
+            assert_eq!(accounts.collection_authority_record, None);
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_legacy_burn_and_sign() {
+    let keys = random_list_of(6, |_| random_pubkey());
+    let instruction = BurnNft {
+        metadata: keys[0],
+        owner: keys[1],
+        mint: keys[2],
+        token_account: keys[3],
+        master_edition_account: keys[4],
+        spl_token_program: spl_token::id(),
+        collection_metadata: Some(keys[5]),
+    }
+    .instruction();
+
+    let result =
+        parse_instruction(&TokenMetadataParser, mpl_token_metadata::ID, &instruction).unwrap();
+    match instruction_data(result.as_ref()) {
+        TokenMetadataInstructionData::BurnNft { accounts } => {
+            assert_eq!(accounts.metadata, keys[0]);
+            assert_eq!(accounts.owner, keys[1]);
+            assert_eq!(accounts.mint, keys[2]);
+            assert_eq!(accounts.token_account, keys[3]);
+            assert_eq!(accounts.collection_metadata, Some(keys[5]));
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+
+    let instruction = SignMetadata {
+        metadata: keys[0],
+        creator: keys[1],
+    }
+    .instruction();
+
+    let result =
+        parse_instruction(&TokenMetadataParser, mpl_token_metadata::ID, &instruction).unwrap();
+    match instruction_data(result.as_ref()) {
+        TokenMetadataInstructionData::SignMetadata { accounts } => {
+            assert_eq!(accounts.metadata, keys[0]);
+            assert_eq!(accounts.creator, keys[1]);
+        }
+        _ => panic!("Unexpected instruction"),
+    }
+}
+
+#[test]
+fn test_unknown_and_invalid_instructions() {
+    // `Utilize` is not decoded.
+    let instruction = Instruction::new_with_bytes(mpl_token_metadata::ID, &[19, 1, 0], vec![]);
+    let result =
+        parse_instruction(&TokenMetadataParser, mpl_token_metadata::ID, &instruction).unwrap();
+    if let ProgramParseResult::TokenMetadataInstruction(ix) = result.result_type() {
+        assert_eq!(ix.discriminator, 19);
+        assert!(matches!(ix.data, TokenMetadataInstructionData::Unknown));
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+
+    // Verify with too few accounts.
+    let instruction = Instruction::new_with_bytes(mpl_token_metadata::ID, &[52, 1], vec![]);
+    assert!(parse_instruction(&TokenMetadataParser, mpl_token_metadata::ID, &instruction).is_err());
+
+    let bundle = InstructionBundle {
+        program: mpl_token_metadata::ID,
+        ..Default::default()
+    };
+    assert!(TokenMetadataParser.handle_instruction(&bundle).is_err());
+}
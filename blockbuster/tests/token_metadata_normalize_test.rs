@@ -0,0 +1,91 @@
+#[cfg(test)]
+use blockbuster::programs::token_metadata::{
+    normalize::{NormalizedMetadata, UriStatus},
+    TokenMetadataAccountData, TokenMetadataAccountState,
+};
+use helpers::*;
+use mpl_token_metadata::{
+    accounts::{MasterEdition, Metadata},
+    types::Key,
+    MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+};
+
+mod helpers;
+
+fn padded(value: &str, len: usize) -> String {
+    format!("{}{}", value, "\0".repeat(len - value.len()))
+}
+
+fn metadata(name: &str, symbol: &str, uri: &str) -> Metadata {
+    Metadata {
+        key: Key::MetadataV1,
+        update_authority: random_pubkey(),
+        mint: random_pubkey(),
+        name: padded(name, MAX_NAME_LENGTH),
+        symbol: padded(symbol, MAX_SYMBOL_LENGTH),
+        uri: padded(uri, MAX_URI_LENGTH),
+        seller_fee_basis_points: 0,
+        creators: None,
+        primary_sale_happened: false,
+        is_mutable: true,
+        edition_nonce: None,
+        token_standard: None,
+        collection: None,
+        uses: None,
+        collection_details: None,
+        programmable_config: None,
+    }
+}
+
+#[test]
+fn test_strip_padding() {
+    let state = TokenMetadataAccountState {
+        key: Key::MetadataV1,
+        data: TokenMetadataAccountData::MetadataV1(metadata(
+            "name",
+            "SYM",
+            "https://example.com/1.json",
+        )),
+    };
+    let normalized = state.normalized_metadata().unwrap();
+    assert_eq!(normalized.name, "name");
+    assert_eq!(normalized.symbol, "SYM");
+    assert_eq!(normalized.uri, "https://example.com/1.json");
+    assert_eq!(normalized.uri_status, UriStatus::Valid);
+    assert!(!normalized.name_or_symbol_truncated);
+
+    // The raw strings are left untouched.
+    match &state.data {
+        TokenMetadataAccountData::MetadataV1(metadata) => {
+            assert_eq!(metadata.name.len(), MAX_NAME_LENGTH)
+        }
+        _ => panic!("Unexpected TokenMetadataAccountData variant"),
+    }
+
+    let edition = TokenMetadataAccountState {
+        key: Key::MasterEditionV2,
+        data: TokenMetadataAccountData::MasterEditionV2(MasterEdition {
+            key: Key::MasterEditionV2,
+            supply: 0,
+            max_supply: None,
+        }),
+    };
+    assert_eq!(edition.normalized_metadata(), None);
+}
+
+#[test]
+fn test_uri_status() {
+    let status = |uri: &str| NormalizedMetadata::new(&metadata("", "", uri)).uri_status;
+
+    assert_eq!(status(""), UriStatus::Empty);
+    assert_eq!(status("ar://abc"), UriStatus::Valid);
+    assert_eq!(status("example.com/1.json"), UriStatus::Invalid);
+    assert_eq!(status("https://example.com/a b"), UriStatus::Invalid);
+    assert_eq!(status("1https://example.com"), UriStatus::Invalid);
+
+    let long = format!("https://example.com/{}", "a".repeat(MAX_URI_LENGTH - 20));
+    assert_eq!(status(&long), UriStatus::Truncated);
+
+    let name = "n".repeat(MAX_NAME_LENGTH);
+    assert!(NormalizedMetadata::new(&metadata(&name, "", "")).name_or_symbol_truncated);
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+use blockbuster::{
+    error::BlockbusterError,
+    programs::token_metadata::{
+        pda::{
+            check_address, find_edition_address, find_edition_marker_address,
+            find_metadata_address, find_token_record_address,
+        },
+        TokenMetadataAccountData, TokenMetadataAccountState,
+    },
+};
+use helpers::*;
+use mpl_token_metadata::{
+    accounts::{MasterEdition, Metadata, TokenRecord},
+    types::{Key, TokenState},
+};
+use solana_sdk::pubkey::Pubkey;
+
+mod helpers;
+
+fn metadata_state(mint: Pubkey) -> TokenMetadataAccountState {
+    TokenMetadataAccountState {
+        key: Key::MetadataV1,
+        data: TokenMetadataAccountData::MetadataV1(Metadata {
+            key: Key::MetadataV1,
+            update_authority: random_pubkey(),
+            mint,
+            name: "name".to_string(),
+            symbol: "SYM".to_string(),
+            uri: "https://example.com".to_string(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: None,
+            collection: None,
+            uses: None,
+            collection_details: None,
+            programmable_config: None,
+        }),
+    }
+}
+
+#[test]
+fn test_validate_metadata_address() {
+    let mint = random_pubkey();
+    let address = find_metadata_address(&mint);
+    assert_eq!(address, Metadata::find_pda(&mint).0);
+
+    let state = metadata_state(mint);
+    assert!(state.validate_address(&address, &mint).is_ok());
+
+    assert!(matches!(
+        state.validate_address(&random_pubkey(), &mint),
+        Err(BlockbusterError::InvalidPda(_, expected)) if expected == address
+    ));
+
+    // Metadata claiming another mint is rejected even at the real PDA.
+    let claimed_mint = random_pubkey();
+    let spoofed = metadata_state(claimed_mint);
+    assert!(matches!(
+        spoofed.validate_address(&address, &mint),
+        Err(BlockbusterError::MintMismatch(actual, expected))
+            if actual == claimed_mint && expected == mint
+    ));
+}
+
+#[test]
+fn test_validate_master_edition_address() {
+    let mint = random_pubkey();
+    let state = TokenMetadataAccountState {
+        key: Key::MasterEditionV2,
+        data: TokenMetadataAccountData::MasterEditionV2(MasterEdition {
+            key: Key::MasterEditionV2,
+            supply: 0,
+            max_supply: Some(10),
+        }),
+    };
+    let address = find_edition_address(&mint);
+    assert!(state.validate_address(&address, &mint).is_ok());
+    assert!(state.validate_address(&random_pubkey(), &mint).is_err());
+
+    // Token records are derived from the token account, which is not stored in them.
+    let token_record = TokenMetadataAccountState {
+        key: Key::TokenRecord,
+        data: TokenMetadataAccountData::TokenRecord(TokenRecord {
+            key: Key::TokenRecord,
+            bump: 255,
+            state: TokenState::Unlocked,
+            rule_set_revision: None,
+            delegate: None,
+            delegate_role: None,
+            locked_transfer: None,
+        }),
+    };
+    assert_eq!(token_record.expected_address(&mint), None);
+    assert!(matches!(
+        token_record.validate_address(&random_pubkey(), &mint),
+        Err(BlockbusterError::PdaNotDerivable)
+    ));
+    let token_account = random_pubkey();
+    assert!(check_address(
+        &TokenRecord::find_pda(&mint, &token_account).0,
+        find_token_record_address(&mint, &token_account)
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_edition_marker_address() {
+    let mint = random_pubkey();
+    assert_eq!(
+        find_edition_marker_address(&mint, 1),
+        find_edition_marker_address(&mint, 247)
+    );
+    assert_ne!(
+        find_edition_marker_address(&mint, 247),
+        find_edition_marker_address(&mint, 248)
+    );
+}
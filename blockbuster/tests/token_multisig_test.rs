@@ -0,0 +1,69 @@
+#[cfg(test)]
+use blockbuster::{
+    program_handler::ProgramParser,
+    programs::{
+        token_account::{TokenAccountParser, TokenProgramAccount},
+        token_extensions::{Token2022AccountParser, TokenExtensionsProgramAccount},
+        ProgramParseResult,
+    },
+};
+use helpers::*;
+use solana_sdk::program_pack::Pack;
+
+mod helpers;
+
+#[test]
+fn test_token_multisig_account() {
+    let signers = random_list_of(3, |_| random_pubkey());
+    let mut multisig = spl_token::state::Multisig {
+        m: 2,
+        n: 3,
+        is_initialized: true,
+        ..Default::default()
+    };
+    multisig.signers[..3].copy_from_slice(&signers);
+    let mut data = vec![0; spl_token::state::Multisig::LEN];
+    spl_token::state::Multisig::pack(multisig, &mut data).unwrap();
+
+    let result = TokenAccountParser.handle_account(&data).unwrap();
+    if let ProgramParseResult::TokenProgramAccount(TokenProgramAccount::Multisig(parsed)) =
+        result.result_type()
+    {
+        assert_eq!(parsed.m, 2);
+        assert_eq!(parsed.n, 3);
+        assert_eq!(&parsed.signers[..3], signers.as_slice());
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+
+    // An uninitialized multisig is rejected like other uninitialized accounts.
+    assert!(TokenAccountParser
+        .handle_account(&[0; spl_token::state::Multisig::LEN])
+        .is_err());
+}
+
+#[test]
+fn test_token_2022_multisig_account() {
+    let signers = random_list_of(2, |_| random_pubkey());
+    let mut multisig = spl_token_2022::state::Multisig {
+        m: 1,
+        n: 2,
+        is_initialized: true,
+        ..Default::default()
+    };
+    multisig.signers[..2].copy_from_slice(&signers);
+    let mut data = vec![0; spl_token_2022::state::Multisig::LEN];
+    spl_token_2022::state::Multisig::pack(multisig, &mut data).unwrap();
+
+    let result = Token2022AccountParser.handle_account(&data).unwrap();
+    if let ProgramParseResult::TokenExtensionsProgramAccount(
+        TokenExtensionsProgramAccount::Multisig(parsed),
+    ) = result.result_type()
+    {
+        assert_eq!(parsed.m, 1);
+        assert_eq!(parsed.n, 2);
+        assert_eq!(&parsed.signers[..2], signers.as_slice());
+    } else {
+        panic!("Unexpected ProgramParseResult variant");
+    }
+}
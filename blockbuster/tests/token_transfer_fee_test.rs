@@ -0,0 +1,51 @@
+#[cfg(test)]
+use blockbuster::programs::token_extensions::extension::{
+    ShadowTransferFee, ShadowTransferFeeConfig,
+};
+
+fn transfer_fee_config() -> ShadowTransferFeeConfig {
+    ShadowTransferFeeConfig {
+        older_transfer_fee: ShadowTransferFee {
+            epoch: 0.into(),
+            maximum_fee: 1_000.into(),
+            transfer_fee_basis_points: 100.into(),
+        },
+        newer_transfer_fee: ShadowTransferFee {
+            epoch: 10.into(),
+            maximum_fee: 5.into(),
+            transfer_fee_basis_points: 50.into(),
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_epoch_fee_selection() {
+    let config = transfer_fee_config();
+    assert_eq!(config.get_epoch_fee(9), &config.older_transfer_fee);
+    assert_eq!(config.get_epoch_fee(10), &config.newer_transfer_fee);
+    assert_eq!(config.get_epoch_fee(11), &config.newer_transfer_fee);
+}
+
+#[test]
+fn test_calculate_fee() {
+    let config = transfer_fee_config();
+
+    // 1% of 1001 is rounded up.
+    assert_eq!(config.calculate_epoch_fee(5, 1_001), Some(11));
+    assert_eq!(
+        config.get_epoch_fee(5).calculate_post_fee_amount(1_001),
+        Some(990)
+    );
+    assert_eq!(config.calculate_inverse_epoch_fee(5, 990), Some(10));
+    assert_eq!(
+        config.get_epoch_fee(5).calculate_pre_fee_amount(990),
+        Some(1_000)
+    );
+
+    // 0.5% of 1001 is capped at the maximum fee.
+    assert_eq!(config.calculate_epoch_fee(10, 1_001), Some(5));
+    assert_eq!(config.calculate_inverse_epoch_fee(10, 1_000_000), Some(5));
+
+    assert_eq!(config.calculate_epoch_fee(10, 0), Some(0));
+}
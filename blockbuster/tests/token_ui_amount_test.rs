@@ -0,0 +1,145 @@
+#[cfg(test)]
+use blockbuster::programs::token_extensions::{
+    extension::{ShadowInterestBearingConfig, ShadowScaledUiAmount},
+    MintAccount, MintAccountExtensions, TokenAccount, TokenAccountExtensions,
+};
+use helpers::*;
+use spl_token_2022::state::{Account, Mint};
+
+mod helpers;
+
+const SECONDS_PER_YEAR: i64 = 31_556_736;
+
+fn mint(decimals: u8, extensions: MintAccountExtensions) -> MintAccount {
+    MintAccount {
+        account: Mint {
+            decimals,
+            is_initialized: true,
+            ..Default::default()
+        },
+        extensions,
+    }
+}
+
+#[test]
+fn test_plain_mint() {
+    let mint = mint(6, MintAccountExtensions::default());
+    assert_eq!(mint.amount_to_ui_amount(1_500_000, 0).unwrap(), "1.5");
+    assert_eq!(mint.try_ui_amount_into_amount("1.5", 0).unwrap(), 1_500_000);
+    assert!(mint.try_ui_amount_into_amount("1.0000001", 0).is_err());
+
+    let token_account = TokenAccount {
+        account: Account {
+            mint: random_pubkey(),
+            amount: 42_000_000,
+            ..Default::default()
+        },
+        extensions: TokenAccountExtensions::default(),
+    };
+    assert_eq!(token_account.ui_amount(&mint, 0).unwrap(), "42");
+}
+
+#[test]
+fn test_interest_bearing_mint() {
+    // A constant 5% rate for one year.
+    let mint = mint(
+        0,
+        MintAccountExtensions {
+            interest_bearing_config: Some(ShadowInterestBearingConfig {
+                initialization_timestamp: 0.into(),
+                pre_update_average_rate: 500.into(),
+                last_update_timestamp: SECONDS_PER_YEAR.into(),
+                current_rate: 500.into(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        mint.amount_to_ui_amount(1, SECONDS_PER_YEAR).unwrap(),
+        "1.0512710963760241"
+    );
+    assert_eq!(
+        mint.try_ui_amount_into_amount("1.0512710963760241", SECONDS_PER_YEAR)
+            .unwrap(),
+        1
+    );
+}
+
+#[test]
+fn test_scaled_ui_amount_mint() {
+    let mint = mint(
+        6,
+        MintAccountExtensions {
+            scaled_ui_amount: Some(ShadowScaledUiAmount {
+                multiplier: 1.5,
+                new_multiplier_effective_timestamp: 100.into(),
+                new_multiplier: 2.0,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    );
+
+    // 1_000_001 * 1.5 is truncated to 1_500_001 base units.
+    assert_eq!(mint.amount_to_ui_amount(1_000_001, 99).unwrap(), "1.500001");
+    assert_eq!(
+        mint.amount_to_ui_amount(1_000_001, 100).unwrap(),
+        "2.000002"
+    );
+    assert_eq!(mint.try_ui_amount_into_amount("3", 99).unwrap(), 2_000_000);
+    assert_eq!(mint.try_ui_amount_into_amount("3", 100).unwrap(), 1_500_000);
+    assert!(mint.try_ui_amount_into_amount("-1", 100).is_err());
+    assert!(mint.try_ui_amount_into_amount("abc", 100).is_err());
+}
+
+// Expected values are taken from the `scaled_ui_amount` extension tests in spl-token-2022, which
+// this crate's pinned release predates.
+#[test]
+fn test_scaled_ui_amount_upstream_vectors() {
+    let config = ShadowScaledUiAmount {
+        multiplier: 5.0,
+        new_multiplier: 5.0,
+        ..Default::default()
+    };
+
+    assert_eq!(config.amount_to_ui_amount(1, 0, 0).unwrap(), "5");
+    assert_eq!(config.amount_to_ui_amount(1, 1, 0).unwrap(), "0.5");
+    assert_eq!(
+        config.amount_to_ui_amount(1, 10, 0).unwrap(),
+        "0.0000000005"
+    );
+    assert_eq!(
+        config.amount_to_ui_amount(10_000_000_000, 10, 0).unwrap(),
+        "5"
+    );
+
+    assert_eq!(config.try_ui_amount_into_amount("5.0", 0, 0).unwrap(), 1);
+    assert_eq!(
+        config
+            .try_ui_amount_into_amount("0.500000000", 1, 0)
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        config
+            .try_ui_amount_into_amount("0.00000000050000000000000000", 10, 0)
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        config.try_ui_amount_into_amount("5.0", 10, 0).unwrap(),
+        10_000_000_000
+    );
+
+    let config = ShadowScaledUiAmount {
+        multiplier: 5.0,
+        new_multiplier_effective_timestamp: 10.into(),
+        new_multiplier: 10.0,
+        ..Default::default()
+    };
+    assert_eq!(config.current_multiplier(9), 5.0);
+    assert_eq!(config.current_multiplier(10), 10.0);
+    assert_eq!(config.amount_to_ui_amount(1, 0, 10).unwrap(), "10");
+}